@@ -0,0 +1,99 @@
+//! Absolute dates like "Mar 5, 2019" used instead of relative output for long timespans.
+
+use super::civil::{gregorian_date, UNIX_EPOCH_RD};
+use super::{Formatter, Language};
+use std::time::{Duration, SystemTime};
+
+impl<L: Language> Formatter<L> {
+    /// Absolute date of a moment in the given year, month and day,
+    /// if the timespan from it is longer than `absolute_after`
+    fn absolute(&self, span: Duration, date: (i32, u32, u32), current_year: i32) -> Option<String> {
+        if span <= self.absolute_after? {
+            return None;
+        }
+        let (year, month, day) = date;
+        let year = if year == current_year {
+            None
+        } else {
+            Some(year)
+        };
+        Some(self.lang.absolute_date(day, month, year))
+    }
+
+    /// Absolute date of `from` in the time zone of `to`, if it should be used
+    #[cfg(feature = "chrono")]
+    pub(crate) fn absolute_chrono<Tz1, Tz2>(
+        &self,
+        from: &chrono::DateTime<Tz1>,
+        to: &chrono::DateTime<Tz2>,
+    ) -> Option<String>
+    where
+        Tz1: chrono::TimeZone,
+        Tz2: chrono::TimeZone,
+    {
+        use chrono::Datelike;
+        let span = to
+            .clone()
+            .signed_duration_since(from.clone())
+            .abs()
+            .to_std()
+            .ok()?;
+        let local = from.with_timezone(&to.timezone());
+        let date = (local.year(), local.month(), local.day());
+        self.absolute(span, date, to.year())
+    }
+
+    /// Format the timespan from `t` to `now` like `convert`, or as an absolute date
+    /// of `t` in UTC when it is longer than set by `absolute_after`.
+    /// Moments in the future give `"???"` unless shown as absolute dates.
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// let f = timeago::Formatter::new();
+    /// let now = SystemTime::now();
+    /// assert_eq!(f.convert_system_time(now - Duration::from_secs(3600), now), "1 hour ago");
+    /// assert_eq!(f.convert_system_time(now + Duration::from_secs(3600), now), "???");
+    /// ```
+    pub fn convert_system_time(&self, t: SystemTime, now: SystemTime) -> String {
+        let span = match now.duration_since(t) {
+            Ok(x) => x,
+            Err(e) => e.duration(),
+        };
+        if self.absolute_after.is_some() {
+            let (date, current) = (utc_date(t), utc_date(now));
+            if let Some(x) = self.absolute(span, date, current.0) {
+                return x;
+            }
+        }
+        if t > now {
+            return "???".to_owned();
+        }
+        self.convert(span)
+    }
+}
+
+/// Year, month and day in UTC
+pub(crate) fn utc_date(t: SystemTime) -> (i32, u32, u32) {
+    let secs = match t.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(x) => x.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs_f64().ceil() as i64),
+    };
+    let (y, m, d) = gregorian_date(UNIX_EPOCH_RD + secs.div_euclid(86400));
+    (y as i32, m, d)
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() {
+    use chrono::{FixedOffset, TimeZone, Utc};
+    let mut f = Formatter::new();
+    f.absolute_after(Duration::from_secs(7 * 86400));
+    let now = Utc.with_ymd_and_hms(2024, 3, 14, 10, 0, 0).unwrap();
+    let from = Utc.with_ymd_and_hms(2024, 3, 4, 22, 0, 0).unwrap();
+    assert_eq!(f.convert_chrono(from, now), "Mar 4");
+    // the date is taken in the time zone of `now`
+    let tz = FixedOffset::east_opt(3 * 3600).unwrap();
+    assert_eq!(f.convert_chrono(from, now.with_timezone(&tz)), "Mar 5");
+    assert_eq!(f.convert_chrono(now, from), "Mar 14");
+    let from = Utc.with_ymd_and_hms(2024, 3, 10, 10, 0, 0).unwrap();
+    assert_eq!(f.convert_chrono(from, now), "4 days ago");
+}
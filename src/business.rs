@@ -0,0 +1,285 @@
+//! Business time for SLA reports, like "3 business days ago", skipping weekends and holidays.
+
+use super::{Chunking, ConvertError, Formatter, Language, TimeUnit};
+use chrono::{Datelike, NaiveDate, NaiveTime, TimeZone, Weekday};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+/// Working hours, working days of the week and holidays used to count business time.
+/// See `Formatter::convert_business`.
+///
+/// Requires `chrono` Cargo feature.
+/// ```
+/// extern crate chrono;
+/// extern crate timeago;
+/// use chrono::{NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+/// use std::time::Duration;
+/// let mut cal = timeago::BusinessCalendar::new();
+/// cal.hours(NaiveTime::from_hms_opt(10, 0, 0).unwrap(), NaiveTime::from_hms_opt(18, 0, 0).unwrap())
+///     .holiday(NaiveDate::from_ymd_opt(2024, 3, 8).unwrap());
+/// assert_eq!(cal.day_length(), Duration::from_secs(8 * 3600));
+/// assert!(!cal.is_business_day(NaiveDate::from_ymd_opt(2024, 3, 9).unwrap()));
+///
+/// // from Thursday noon to Monday 11:00, with Friday a holiday
+/// let from = Utc.with_ymd_and_hms(2024, 3, 7, 12, 0, 0).unwrap();
+/// let to = Utc.with_ymd_and_hms(2024, 3, 11, 11, 0, 0).unwrap();
+/// assert_eq!(cal.working_time(from, to, &Utc), Duration::from_secs(7 * 3600));
+///
+/// // 262 weekdays of 2024 without the holiday
+/// let year_start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// let year_end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let hours = cal.working_time(year_start, year_end, &Utc).as_secs() / 3600;
+/// assert_eq!(hours, 261 * 8);
+///
+/// cal.workdays(&[Weekday::Fri, Weekday::Sat]);
+/// assert_eq!(cal.working_time(from, to, &Utc), Duration::from_secs(8 * 3600));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BusinessCalendar {
+    start: NaiveTime,
+    end: NaiveTime,
+    /// Working days of the week, from Monday
+    workdays: [bool; 7],
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BusinessCalendar {
+    /// Calendar with working hours from 9:00 to 17:00, Monday to Friday, and no holidays
+    pub fn new() -> BusinessCalendar {
+        BusinessCalendar {
+            start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            workdays: [true, true, true, true, true, false, false],
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// Set the working hours of each business day, in the local time of the time zone used.
+    /// `start` must be before `end`.
+    pub fn hours(&mut self, start: NaiveTime, end: NaiveTime) -> &mut Self {
+        assert!(start < end);
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    /// Set the working days of the week. Default is Monday to Friday.
+    pub fn workdays(&mut self, days: &[Weekday]) -> &mut Self {
+        self.workdays = [false; 7];
+        for d in days {
+            self.workdays[d.num_days_from_monday() as usize] = true;
+        }
+        self
+    }
+
+    /// Add a holiday which is not a business day even if it falls on a working day of the week
+    pub fn holiday(&mut self, date: NaiveDate) -> &mut Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Whether the date is a working day of the week and not a holiday
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        self.workdays[date.weekday().num_days_from_monday() as usize]
+            && !self.holidays.contains(&date)
+    }
+
+    /// Working time of a business day
+    pub fn day_length(&self) -> Duration {
+        (self.end - self.start).to_std().unwrap_or_default()
+    }
+
+    /// Working time between the moments, with working hours taken in the time zone `tz`.
+    /// The order of `from` and `to` does not matter.
+    ///
+    /// The days between the first and the last one count as whole business days of `day_length`,
+    /// so DST transitions within the working hours of those days are not taken into account.
+    pub fn working_time<Tz1, Tz2, Tz3>(
+        &self,
+        from: chrono::DateTime<Tz1>,
+        to: chrono::DateTime<Tz2>,
+        tz: &Tz3,
+    ) -> Duration
+    where
+        Tz1: TimeZone,
+        Tz2: TimeZone,
+        Tz3: TimeZone,
+    {
+        let (from, to) = (from.with_timezone(tz), to.with_timezone(tz));
+        let (from, to) = if from <= to { (from, to) } else { (to, from) };
+        let (first, last) = (from.date_naive(), to.date_naive());
+        let in_day = |d: NaiveDate| {
+            if !self.is_business_day(d) {
+                return Duration::new(0, 0);
+            }
+            let start = tz.from_local_datetime(&d.and_time(self.start)).earliest();
+            let end = tz.from_local_datetime(&d.and_time(self.end)).latest();
+            match (start, end) {
+                (Some(start), Some(end)) => {
+                    let start = start.max(from.clone());
+                    let end = end.min(to.clone());
+                    (end - start).to_std().unwrap_or_default()
+                }
+                _ => Duration::new(0, 0),
+            }
+        };
+        let mut ret = in_day(first);
+        if last > first {
+            ret += in_day(last);
+        }
+        if let (Some(next), Some(prev)) = (first.succ_opt(), last.pred_opt()) {
+            if next <= prev {
+                ret += self.day_length() * self.business_days(next, prev);
+            }
+        }
+        ret
+    }
+
+    /// Number of business days from `first` to `last` inclusive, counting whole weeks at once
+    fn business_days(&self, first: NaiveDate, last: NaiveDate) -> u32 {
+        let workday = |d: &NaiveDate| self.workdays[d.weekday().num_days_from_monday() as usize];
+        let days = (last - first).num_days() as u32 + 1;
+        let per_week = self.workdays.iter().filter(|x| **x).count() as u32;
+        let rest = first + chrono::Duration::days(i64::from(days / 7 * 7));
+        let rest = rest.iter_days().take((days % 7) as usize).filter(workday);
+        let holidays = self.holidays.range(first..=last).filter(|d| workday(d));
+        days / 7 * per_week + rest.count() as u32 - holidays.count() as u32
+    }
+}
+
+impl<L: Language> Formatter<L> {
+    /// Format the working time between `from` and `now` counted by the calendar, with
+    /// working hours taken in the time zone `tz`, like "3 business days ago" or "5 working hours ago".
+    /// Whole working days of the calendar's length are business days, the rest is working hours
+    /// and minutes, limited by `num_items`, `min_unit`, `max_unit` and `chunking`.
+    /// Working time longer than `max_duration` gives `too_high`.
+    /// `from` should come before `now`, otherwise `"???"` will be returned.
+    /// Other settings like custom units, thresholds and qualifiers are not used.
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::{TimeZone, Utc};
+    /// use std::time::Duration;
+    /// use timeago::Chunking;
+    /// let cal = timeago::BusinessCalendar::new();
+    /// let mut f = timeago::Formatter::new();
+    /// let now = Utc.with_ymd_and_hms(2024, 3, 11, 15, 0, 0).unwrap(); // Monday
+    /// let t = |d, h, m| Utc.with_ymd_and_hms(2024, 3, d, h, m, 0).unwrap();
+    /// assert_eq!(f.convert_business(t(6, 15, 0), now, &Utc, &cal), "3 business days ago");
+    /// assert_eq!(f.convert_business(t(8, 15, 0), now, &Utc, &cal), "1 business day ago");
+    /// assert_eq!(f.convert_business(t(9, 12, 0), now, &Utc, &cal), "6 working hours ago");
+    /// assert_eq!(f.convert_business(t(11, 14, 20), now, &Utc, &cal), "40 minutes ago");
+    /// assert_eq!(f.convert_business(t(10, 12, 0), now, &Utc, &cal), "6 working hours ago");
+    /// assert_eq!(f.convert_business(t(12, 10, 0), now, &Utc, &cal), "???");
+    /// f.num_items(2);
+    /// assert_eq!(f.convert_business(t(7, 12, 30), now, &Utc, &cal), "2 business days 2 working hours ago");
+    /// assert_eq!(f.convert_business(t(6, 14, 30), now, &Utc, &cal), "3 business days 30 minutes ago");
+    /// f.chunking(Chunking::Adjacent);
+    /// assert_eq!(f.convert_business(t(6, 14, 30), now, &Utc, &cal), "3 business days ago");
+    /// f.max_duration(Duration::from_secs(5 * 8 * 3600));
+    /// assert_eq!(f.convert_business(t(1, 15, 0), now, &Utc, &cal), "old");
+    /// ```
+    pub fn convert_business<Tz1, Tz2, Tz3>(
+        &self,
+        from: chrono::DateTime<Tz1>,
+        now: chrono::DateTime<Tz2>,
+        tz: &Tz3,
+        calendar: &BusinessCalendar,
+    ) -> String
+    where
+        Tz1: TimeZone,
+        Tz2: TimeZone,
+        Tz3: TimeZone,
+    {
+        if from.naive_utc() > now.naive_utc() {
+            return "???".to_owned();
+        }
+        let d = calendar.working_time(from, now, tz);
+        if d > self.max_duration {
+            return self.convert_error(ConvertError::TooHigh);
+        }
+        let day = calendar.day_length();
+        let context = self.context(None);
+        let (days, rem) = if self.max_unit >= TimeUnit::Days && !day.is_zero() {
+            let days = d.as_nanos() / day.as_nanos();
+            (days as u64, d - day * days as u32)
+        } else {
+            (0, d)
+        };
+        let (hours, rem) = if self.max_unit >= TimeUnit::Hours {
+            (rem.as_secs() / 3600, rem.as_secs() % 3600)
+        } else {
+            (0, rem.as_secs())
+        };
+        let minutes = rem / 60;
+        let items = [
+            (days, self.lang.business_word(TimeUnit::Days, days)),
+            (hours, self.lang.business_word(TimeUnit::Hours, hours)),
+            (minutes, self.get_word(TimeUnit::Minutes, minutes, context)),
+        ];
+        let allowed = [TimeUnit::Days, TimeUnit::Hours, TimeUnit::Minutes]
+            .iter()
+            .filter(|tu| **tu >= self.min_unit)
+            .count();
+        let items = items[..allowed].iter().skip_while(|(x, _)| *x == 0);
+        let items: Vec<_> = match self.chunking {
+            Chunking::Free => items
+                .filter(|(x, _)| *x != 0)
+                .take(self.num_items)
+                .collect(),
+            Chunking::Adjacent => items
+                .take(self.num_items)
+                .take_while(|(x, _)| *x != 0)
+                .collect(),
+            Chunking::AdjacentWithZeros => items.take(self.num_items).collect(),
+        };
+        let ret = items.iter().rev().fold(String::new(), |rest, (x, word)| {
+            self.join_word(*x, word, rest)
+        });
+        if ret.is_empty() {
+            return self.convert_error(ConvertError::TooLow);
+        }
+        self.relate(ret, context)
+    }
+}
+
+#[cfg(feature = "translations")]
+#[test]
+fn test_languages() {
+    use super::languages::{german::German, russian::Russian};
+    use chrono::Utc;
+    let mut cal = BusinessCalendar::new();
+    cal.holiday(NaiveDate::from_ymd_opt(2024, 3, 8).unwrap());
+    let now = Utc.with_ymd_and_hms(2024, 3, 11, 15, 0, 0).unwrap();
+    let t = |d, h| Utc.with_ymd_and_hms(2024, 3, d, h, 0, 0).unwrap();
+    let f = Formatter::with_language(Russian);
+    assert_eq!(
+        f.convert_business(t(6, 15), now, &Utc, &cal),
+        "2 рабочих дня назад"
+    );
+    assert_eq!(
+        f.convert_business(t(1, 15), now, &Utc, &cal),
+        "5 рабочих дней назад"
+    );
+    assert_eq!(
+        f.convert_business(t(11, 14), now, &Utc, &cal),
+        "1 рабочий час назад"
+    );
+    let f = Formatter::with_language(German);
+    assert_eq!(
+        f.convert_business(t(6, 15), now, &Utc, &cal),
+        "vor 2 Werktagen"
+    );
+    assert_eq!(
+        f.convert_business(t(11, 10), now, &Utc, &cal),
+        "vor 5 Arbeitsstunden"
+    );
+}
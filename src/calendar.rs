@@ -0,0 +1,121 @@
+//! Calendar-relative phrases like "yesterday at 14:05" or "last Tuesday".
+
+use super::{Formatter, Language, Relation, TimeUnit, WordContext};
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveDate, TimeZone, Timelike};
+
+/// Number of calendar days and weeks (Monday-based) from `from` to `to`,
+/// as well as difference in months and in years
+pub(crate) fn calendar_diff(from: NaiveDate, to: NaiveDate) -> (i64, i64, i64, i64) {
+    let days = to.signed_duration_since(from).num_days();
+    let monday =
+        |x: NaiveDate| x - ChronoDuration::days(i64::from(x.weekday().num_days_from_monday()));
+    let weeks = monday(to).signed_duration_since(monday(from)).num_days() / 7;
+    let months = i64::from(to.year() * 12 + to.month() as i32)
+        - i64::from(from.year() * 12 + from.month() as i32);
+    let years = i64::from(to.year() - from.year());
+    (days, weeks, months, years)
+}
+
+impl<L: Language> Formatter<L> {
+    /// Format the moment `from` relative to `now` using calendar boundaries:
+    /// "yesterday at 14:05", "last Tuesday", "last week", "last month", "last year"
+    /// or similar phrases for the future, like "tomorrow at 09:00" or "next Tuesday".
+    ///
+    /// Requires `chrono` Cargo feature.
+    ///
+    /// Calendar days are taken in the time zone of the arguments, so convert them
+    /// to the caller's time zone first. Weeks start on Monday.
+    /// Moments earlier the same day are formatted like `convert_chrono` does, later the same
+    /// day give "today at ...". Spans not covered by the phrases above fall back
+    /// to `convert_chrono` for the past and to the `In` relation, like "in 3 years", for the future.
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::TimeZone;
+    /// let f = timeago::Formatter::new();
+    /// let tz = chrono::FixedOffset::east_opt(3 * 3600).unwrap();
+    /// let now = tz.with_ymd_and_hms(2024, 3, 14, 10, 0, 0).unwrap(); // Thursday
+    /// let t = |m, d, h, min| tz.with_ymd_and_hms(2024, m, d, h, min, 0).unwrap();
+    /// assert_eq!(f.convert_calendar(t(3, 14, 8, 0), now), "2 hours ago");
+    /// assert_eq!(f.convert_calendar(t(3, 14, 18, 30), now), "today at 18:30");
+    /// assert_eq!(f.convert_calendar(t(3, 13, 14, 5), now), "yesterday at 14:05");
+    /// assert_eq!(f.convert_calendar(t(3, 15, 9, 0), now), "tomorrow at 09:00");
+    /// assert_eq!(f.convert_calendar(t(3, 12, 23, 0), now), "last Tuesday");
+    /// assert_eq!(f.convert_calendar(t(3, 19, 12, 0), now), "next Tuesday");
+    /// assert_eq!(f.convert_calendar(t(3, 5, 12, 0), now), "last week");
+    /// assert_eq!(f.convert_calendar(t(2, 20, 12, 0), now), "last month");
+    /// assert_eq!(f.convert_calendar(t(4, 20, 12, 0), now), "next month");
+    /// assert_eq!(f.convert_calendar(tz.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap(), now), "last year");
+    /// assert_eq!(f.convert_calendar(tz.with_ymd_and_hms(2020, 5, 1, 0, 0, 0).unwrap(), now), "3 years ago");
+    /// assert_eq!(f.convert_calendar(tz.with_ymd_and_hms(2027, 5, 1, 0, 0, 0).unwrap(), now), "in 3 years");
+    /// ```
+    pub fn convert_calendar<Tz>(&self, from: DateTime<Tz>, now: DateTime<Tz>) -> String
+    where
+        Tz: TimeZone,
+    {
+        let (days, weeks, months, years) = calendar_diff(from.date_naive(), now.date_naive());
+        let future = from > now;
+        let time = || {
+            let t = from.naive_local().time();
+            format!("{:02}:{:02}", t.hour(), t.minute())
+        };
+        let lang = &self.lang;
+
+        match days {
+            0 if future => return lang.day_at_time(lang.today(), &time()),
+            0 => return self.convert_chrono(from, now),
+            1 => return lang.day_at_time(lang.yesterday(), &time()),
+            -1 => return lang.day_at_time(lang.tomorrow(), &time()),
+            2..=6 | -6..=-2 => {
+                return lang
+                    .weekday_phrase(from.weekday().num_days_from_monday(), future)
+                    .to_owned()
+            }
+            _ => (),
+        }
+        match (weeks, months, years) {
+            (1, _, _) => lang.period_phrase(TimeUnit::Weeks, false).to_owned(),
+            (-1, _, _) => lang.period_phrase(TimeUnit::Weeks, true).to_owned(),
+            (_, 1, _) => lang.period_phrase(TimeUnit::Months, false).to_owned(),
+            (_, -1, _) => lang.period_phrase(TimeUnit::Months, true).to_owned(),
+            (_, _, 1) => lang.period_phrase(TimeUnit::Years, false).to_owned(),
+            (_, _, -1) => lang.period_phrase(TimeUnit::Years, true).to_owned(),
+            _ if future => {
+                let d = (from - now).to_std().unwrap_or_default();
+                let context = WordContext {
+                    relation: Relation::In,
+                    qualifier: None,
+                };
+                match self.try_convert_in(d, context) {
+                    Ok(x) => x,
+                    Err(e) => self.convert_error(e),
+                }
+            }
+            _ => self.convert_chrono(from, now),
+        }
+    }
+}
+
+#[cfg(feature = "translations")]
+#[test]
+fn test_russian() {
+    use super::languages::russian::Russian;
+    use chrono::Utc;
+    let f = Formatter::with_language(Russian);
+    let now = Utc.with_ymd_and_hms(2024, 3, 14, 10, 0, 0).unwrap();
+    let t = |m, d, h, min| Utc.with_ymd_and_hms(2024, m, d, h, min, 0).unwrap();
+    assert_eq!(f.convert_calendar(t(3, 13, 14, 5), now), "вчера в 14:05");
+    assert_eq!(
+        f.convert_calendar(t(3, 11, 9, 0), now),
+        "в прошлый понедельник"
+    );
+    assert_eq!(
+        f.convert_calendar(t(3, 16, 9, 0), now),
+        "в следующую субботу"
+    );
+    assert_eq!(f.convert_calendar(t(3, 4, 9, 0), now), "на прошлой неделе");
+    assert_eq!(f.convert_calendar(t(2, 1, 9, 0), now), "в прошлом месяце");
+    assert_eq!(f.convert_calendar(t(3, 1, 9, 0), now), "1 неделю назад");
+    assert_eq!(f.convert_calendar(t(6, 1, 9, 0), now), "через 2 месяца");
+}
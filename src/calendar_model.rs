@@ -0,0 +1,92 @@
+//! Lengths of months and years used when converting durations to these units.
+
+use super::{TimeUnit, S_IN_MNTH};
+use std::time::Duration;
+
+/// Lengths of a month and a year, as `std::time::Duration` has no calendar behind it.
+/// Other units always have their fixed `TimeUnit::min_duration`.
+///
+/// Set it with `Formatter::calendar` or use it with `format_5chars_with`.
+/// ```
+/// use std::time::Duration;
+/// use timeago::{CalendarModel, TimeUnit};
+/// let m = CalendarModel::THIRTY_360;
+/// assert_eq!(m.unit_length(TimeUnit::Months), Duration::from_secs(30 * 86400));
+/// assert_eq!(m.unit_length(TimeUnit::Years), Duration::from_secs(360 * 86400));
+/// assert_eq!(m.unit_length(TimeUnit::Days), Duration::from_secs(86400));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CalendarModel {
+    month: Duration,
+    year: Duration,
+}
+
+impl CalendarModel {
+    /// Average Gregorian month of about 30.4 days and a year of 12 such months. This is the default.
+    pub const DEFAULT: CalendarModel = CalendarModel {
+        month: Duration::from_secs(S_IN_MNTH),
+        year: Duration::from_secs(12 * S_IN_MNTH),
+    };
+
+    /// 30-day months and 360-day years, as in the 30/360 day count convention of finance
+    pub const THIRTY_360: CalendarModel = CalendarModel {
+        month: Duration::from_secs(30 * 86400),
+        year: Duration::from_secs(360 * 86400),
+    };
+
+    /// Julian years of 365.25 days, as used in astronomy, and months of a twelfth of that
+    pub const JULIAN: CalendarModel = CalendarModel {
+        month: Duration::from_secs(31_557_600 / 12),
+        year: Duration::from_secs(31_557_600),
+    };
+
+    /// 30-day months, as in billing periods, and common years of 365 days
+    pub const THIRTY_DAY_MONTHS: CalendarModel = CalendarModel {
+        month: Duration::from_secs(30 * 86400),
+        year: Duration::from_secs(365 * 86400),
+    };
+
+    /// Model with the specified lengths. Both should be whole seconds, longer than a week,
+    /// and the year should not be shorter than the month.
+    /// ```
+    /// use std::time::Duration;
+    /// // lunar months
+    /// const LUNAR: timeago::CalendarModel = timeago::CalendarModel::new(
+    ///     Duration::from_secs(2_551_443),
+    ///     Duration::from_secs(12 * 2_551_443),
+    /// );
+    /// let mut f = timeago::Formatter::new();
+    /// f.calendar(LUNAR);
+    /// assert_eq!(f.convert(Duration::from_secs(30 * 86400)), "1 month ago");
+    /// ```
+    pub const fn new(month: Duration, year: Duration) -> CalendarModel {
+        assert!(month.subsec_nanos() == 0 && year.subsec_nanos() == 0);
+        assert!(month.as_secs() > 7 * 86400 && year.as_secs() >= month.as_secs());
+        CalendarModel { month, year }
+    }
+
+    /// Length of a month
+    pub fn month_length(&self) -> Duration {
+        self.month
+    }
+
+    /// Length of a year
+    pub fn year_length(&self) -> Duration {
+        self.year
+    }
+
+    /// Length of the unit in this model
+    pub fn unit_length(&self, tu: TimeUnit) -> Duration {
+        match tu {
+            TimeUnit::Months => self.month,
+            TimeUnit::Years => self.year,
+            _ => tu.min_duration(),
+        }
+    }
+}
+
+impl Default for CalendarModel {
+    fn default() -> CalendarModel {
+        CalendarModel::DEFAULT
+    }
+}
@@ -0,0 +1,537 @@
+//! Civil calendars for counting whole months and years between two dates.
+
+#[cfg(feature = "chrono")]
+use super::{Chunking, ConvertError, Formatter, Language, TimeUnit};
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+/// Calendar used to count months and years between two moments,
+/// independently of the language of the output. See `Formatter::civil_calendar`.
+///
+/// Days are identified by their Rata Die number, i.e. the day count where 1 January of year 1
+/// of the proleptic Gregorian calendar is 1, like in `chrono::Datelike::num_days_from_ce`.
+/// ```
+/// use timeago::CivilCalendar::*;
+/// let rd = 738_959; // 14 March 2024
+/// assert_eq!(Gregorian.date(rd), (2024, 3, 14));
+/// assert_eq!(ThaiBuddhist.date(rd), (2567, 3, 14));
+/// assert_eq!(IslamicTabular.date(rd), (1445, 9, 4));
+/// assert_eq!(SolarHijri.date(rd), (1402, 12, 24));
+/// assert_eq!(Hebrew.date(rd), (5784, 7, 4)); // 4 Adar II
+/// assert_eq!(Hebrew.rata_die(5784, 7, 4), rd);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CivilCalendar {
+    /// Proleptic Gregorian calendar
+    Gregorian,
+    /// Arithmetic Islamic calendar with the common 30-year cycle of leap years and
+    /// the epoch of 16 July 622 (Julian). It may differ by a day or two from
+    /// calendars based on moon sighting.
+    IslamicTabular,
+    /// Solar Hijri (Persian) calendar, with leap years by the 33-year arithmetic rule,
+    /// which agrees with the official calendar of Iran in the current era
+    SolarHijri,
+    /// Hebrew calendar. Months are numbered from Tishrei, so that in leap years
+    /// Adar I is 6 and Adar II is 7, and in common years Adar is 6.
+    Hebrew,
+    /// Thai solar calendar: Gregorian months with the Buddhist Era year (Gregorian + 543)
+    ThaiBuddhist,
+}
+
+/// Rata Die of 1 January 1970
+pub(crate) const UNIX_EPOCH_RD: i64 = 719_163;
+
+fn gregorian_leap(y: i64) -> bool {
+    y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
+}
+
+fn gregorian_rd(y: i64, m: u32, d: u32) -> i64 {
+    let (m, d) = (i64::from(m), i64::from(d));
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468 + UNIX_EPOCH_RD
+}
+
+pub(crate) fn gregorian_date(rd: i64) -> (i64, u32, u32) {
+    let z = rd - UNIX_EPOCH_RD + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+fn islamic_leap(y: i64) -> bool {
+    (14 + 11 * y).rem_euclid(30) < 11
+}
+
+fn solar_hijri_leap(y: i64) -> bool {
+    (8 * y + 29).rem_euclid(33) < 8
+}
+
+/// Number of leap years among 1..y of the Solar Hijri calendar
+fn solar_hijri_leaps_before(y: i64) -> i64 {
+    let cycles = (y - 1).div_euclid(33);
+    let mut ret = cycles * 8;
+    for k in (cycles * 33 + 1)..y {
+        ret += i64::from(solar_hijri_leap(k));
+    }
+    ret
+}
+
+/// Rata Die of the day before 1 Farvardin 1 by the arithmetic rule
+const SOLAR_HIJRI_EPOCH: i64 = 226_894;
+
+/// Epoch of the Hebrew calendar as used in the new year computation
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+fn hebrew_leap(y: i64) -> bool {
+    (7 * y + 1).rem_euclid(19) < 7
+}
+
+/// Months before the year `y` since the epoch
+fn hebrew_months_before(y: i64) -> i64 {
+    (235 * y - 234).div_euclid(19)
+}
+
+/// Days from the epoch to the molad of Tishrei, with the first postponement rule
+fn hebrew_elapsed_days(y: i64) -> i64 {
+    let months = hebrew_months_before(y);
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+fn hebrew_new_year(y: i64) -> i64 {
+    let (ny0, ny1, ny2) = (
+        hebrew_elapsed_days(y - 1),
+        hebrew_elapsed_days(y),
+        hebrew_elapsed_days(y + 1),
+    );
+    let correction = if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    };
+    HEBREW_EPOCH + ny1 + correction
+}
+
+fn hebrew_month_length(y: i64, m: u32) -> u32 {
+    let year_length = hebrew_new_year(y + 1) - hebrew_new_year(y);
+    // months after Adar are shifted in leap years
+    let m = if hebrew_leap(y) || m < 6 { m } else { m + 1 };
+    match m {
+        2 if year_length % 10 == 5 => 30,
+        3 if year_length % 10 == 3 => 29,
+        1 | 3 | 5 | 8 | 10 | 12 => 30,
+        6 if hebrew_leap(y) => 30,
+        _ => 29,
+    }
+}
+
+impl CivilCalendar {
+    /// Number of months in the year `y`
+    pub fn months_in_year(self, y: i64) -> u32 {
+        match self {
+            CivilCalendar::Hebrew if hebrew_leap(y) => 13,
+            _ => 12,
+        }
+    }
+
+    /// Number of days in the month `m` (starting from 1) of the year `y`
+    /// ```
+    /// use timeago::CivilCalendar::*;
+    /// assert_eq!(Gregorian.days_in_month(2024, 2), 29);
+    /// assert_eq!(IslamicTabular.days_in_month(1446, 12), 29);
+    /// assert_eq!(SolarHijri.days_in_month(1403, 12), 30);
+    /// assert_eq!(Hebrew.days_in_month(5785, 2), 30); // long Heshvan
+    /// ```
+    pub fn days_in_month(self, y: i64, m: u32) -> u32 {
+        match self {
+            CivilCalendar::Gregorian | CivilCalendar::ThaiBuddhist => {
+                let y = if self == CivilCalendar::ThaiBuddhist {
+                    y - 543
+                } else {
+                    y
+                };
+                match m {
+                    2 if gregorian_leap(y) => 29,
+                    2 => 28,
+                    4 | 6 | 9 | 11 => 30,
+                    _ => 31,
+                }
+            }
+            CivilCalendar::IslamicTabular => match m {
+                12 if islamic_leap(y) => 30,
+                _ if m % 2 == 1 => 30,
+                _ => 29,
+            },
+            CivilCalendar::SolarHijri => match m {
+                1..=6 => 31,
+                12 if !solar_hijri_leap(y) => 29,
+                _ => 30,
+            },
+            CivilCalendar::Hebrew => hebrew_month_length(y, m),
+        }
+    }
+
+    /// Rata Die of the given date. The month and the day are expected to be valid.
+    pub fn rata_die(self, y: i64, m: u32, d: u32) -> i64 {
+        match self {
+            CivilCalendar::Gregorian => gregorian_rd(y, m, d),
+            CivilCalendar::ThaiBuddhist => gregorian_rd(y - 543, m, d),
+            CivilCalendar::IslamicTabular => {
+                let (m, d) = (i64::from(m), i64::from(d));
+                227_014 + (y - 1) * 354 + (3 + 11 * y).div_euclid(30) + 29 * (m - 1) + m / 2 + d
+            }
+            CivilCalendar::SolarHijri => {
+                let m = i64::from(m);
+                let before_month = if m <= 7 {
+                    31 * (m - 1)
+                } else {
+                    186 + 30 * (m - 7)
+                };
+                SOLAR_HIJRI_EPOCH
+                    + 365 * (y - 1)
+                    + solar_hijri_leaps_before(y)
+                    + before_month
+                    + i64::from(d)
+            }
+            CivilCalendar::Hebrew => {
+                let before_month: u32 = (1..m).map(|x| hebrew_month_length(y, x)).sum();
+                hebrew_new_year(y) + i64::from(before_month) + i64::from(d) - 1
+            }
+        }
+    }
+
+    /// Year, month and day of the given Rata Die
+    pub fn date(self, rd: i64) -> (i64, u32, u32) {
+        let (mut y, approx_year) = match self {
+            CivilCalendar::Gregorian => return gregorian_date(rd),
+            CivilCalendar::ThaiBuddhist => {
+                let (y, m, d) = gregorian_date(rd);
+                return (y + 543, m, d);
+            }
+            CivilCalendar::IslamicTabular => (0, 10_631.0 / 30.0),
+            CivilCalendar::SolarHijri => (0, 365.2424),
+            CivilCalendar::Hebrew => (0, 365.2468),
+        };
+        let epoch = self.rata_die(1, 1, 1);
+        y += ((rd - epoch) as f64 / approx_year).floor() as i64 + 1;
+        while self.rata_die(y + 1, 1, 1) <= rd {
+            y += 1;
+        }
+        while self.rata_die(y, 1, 1) > rd {
+            y -= 1;
+        }
+        let mut m = 1;
+        while m < self.months_in_year(y) && self.rata_die(y, m + 1, 1) <= rd {
+            m += 1;
+        }
+        (y, m, (rd - self.rata_die(y, m, 1)) as u32 + 1)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl CivilCalendar {
+    /// Number of months from the start of the calendar to the beginning of the given month
+    pub(crate) fn month_index(self, y: i64, m: u32) -> i64 {
+        let before_year = match self {
+            CivilCalendar::Hebrew => hebrew_months_before(y),
+            _ => y * 12,
+        };
+        before_year + i64::from(m) - 1
+    }
+
+    /// Year and month of the given month index
+    pub(crate) fn month_at(self, index: i64) -> (i64, u32) {
+        let mut y = match self {
+            CivilCalendar::Hebrew => (index * 19).div_euclid(235),
+            _ => index.div_euclid(12),
+        };
+        while self.month_index(y + 1, 1) <= index {
+            y += 1;
+        }
+        while self.month_index(y, 1) > index {
+            y -= 1;
+        }
+        (y, (index - self.month_index(y, 1)) as u32 + 1)
+    }
+
+    /// Same month as `m` of the year `from` in the year `to`
+    pub(crate) fn same_month(self, from: i64, m: u32, to: i64) -> u32 {
+        if self != CivilCalendar::Hebrew {
+            return m;
+        }
+        match (hebrew_leap(from), hebrew_leap(to)) {
+            (true, false) if m >= 7 => m - 1,
+            (false, true) if m >= 6 => m + 1,
+            _ => m,
+        }
+    }
+}
+
+/// Move `t` by `months` months in the calendar, clamping the day to the length of the target month
+#[cfg(feature = "chrono")]
+fn add_months(cal: CivilCalendar, t: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let (y, m, d) = cal.date(i64::from(t.date().num_days_from_ce()));
+    let (y2, m2) = cal.month_at(cal.month_index(y, m) + months);
+    let d2 = d.min(cal.days_in_month(y2, m2));
+    let rd = i32::try_from(cal.rata_die(y2, m2, d2)).ok()?;
+    Some(NaiveDate::from_num_days_from_ce_opt(rd)?.and_time(t.time()))
+}
+
+/// Move `t` by `years` years in the calendar, keeping the month and clamping the day
+#[cfg(feature = "chrono")]
+fn add_years(cal: CivilCalendar, t: NaiveDateTime, years: i64) -> Option<NaiveDateTime> {
+    let (y, m, d) = cal.date(i64::from(t.date().num_days_from_ce()));
+    let m2 = cal.same_month(y, m, y + years);
+    let d2 = d.min(cal.days_in_month(y + years, m2));
+    let rd = i32::try_from(cal.rata_die(y + years, m2, d2)).ok()?;
+    Some(NaiveDate::from_num_days_from_ce_opt(rd)?.and_time(t.time()))
+}
+
+/// Number of whole `step`s from `from` not exceeding `to`, and the moment after them
+#[cfg(feature = "chrono")]
+fn count_whole(
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    estimate: i64,
+    step: impl Fn(NaiveDateTime, i64) -> Option<NaiveDateTime>,
+) -> (i64, NaiveDateTime) {
+    let mut n = estimate.max(0);
+    while n > 0 && step(from, n).map_or(true, |x| x > to) {
+        n -= 1;
+    }
+    while step(from, n + 1).map_or(false, |x| x <= to) {
+        n += 1;
+    }
+    (n, step(from, n).unwrap_or(from))
+}
+
+#[cfg(feature = "chrono")]
+impl<L: Language> Formatter<L> {
+    /// Format the span between local date and times counting months and years in the calendar
+    pub(crate) fn convert_civil(
+        &self,
+        cal: CivilCalendar,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<String, ConvertError> {
+        let d = to
+            .signed_duration_since(from)
+            .to_std()
+            .map_err(|_| ConvertError::Negative)?;
+        if d > self.max_duration {
+            return Err(ConvertError::TooHigh);
+        }
+        if self.max_unit < TimeUnit::Months || self.min_unit > TimeUnit::Years {
+            return self.try_convert(d);
+        }
+
+        let mut items = Vec::new();
+        let mut anchor = from;
+        let rd = |t: NaiveDateTime| i64::from(t.date().num_days_from_ce());
+        if self.max_unit >= TimeUnit::Years {
+            let estimate = cal.date(rd(to)).0 - cal.date(rd(from)).0;
+            let (years, a) = count_whole(anchor, to, estimate, |t, n| add_years(cal, t, n));
+            if years > 0 {
+                items.push((TimeUnit::Years, years));
+                anchor = a;
+            }
+        }
+        if self.min_unit <= TimeUnit::Months {
+            let index = |t| {
+                let (y, m, _) = cal.date(rd(t));
+                cal.month_index(y, m)
+            };
+            let estimate = index(to) - index(anchor);
+            let (months, a) = count_whole(anchor, to, estimate, |t, n| add_months(cal, t, n));
+            if months > 0 || (!items.is_empty() && self.chunking == Chunking::AdjacentWithZeros) {
+                items.push((TimeUnit::Months, months));
+                anchor = a;
+            } else if !items.is_empty() && self.chunking == Chunking::Adjacent {
+                anchor = to;
+            }
+        }
+        if items.is_empty() {
+            if d < self.calendar.unit_length(TimeUnit::Months) {
+                return self.try_convert(d);
+            }
+            if self.min_unit > TimeUnit::Months {
+                return Err(ConvertError::TooLow);
+            }
+        }
+        items.truncate(self.num_items);
+        let context = self.context(None);
+
+        let rem = to
+            .signed_duration_since(anchor)
+            .to_std()
+            .unwrap_or_default();
+        let items_left = self.num_items - items.len();
+        let rest = match (items.last(), self.chunking) {
+            (Some((tu, _)), Chunking::Adjacent | Chunking::AdjacentWithZeros) => {
+                self.convert_rest(*tu, rem, items_left, context)?
+            }
+            _ => {
+                // a remainder may be longer than the average month, but is never a month
+                let tu = self.dominant_unit(rem).min(TimeUnit::Weeks);
+                if items_left == 0 || tu < self.min_unit {
+                    "".to_owned()
+                } else {
+                    self.convert_impl(rem, items_left, Some(tu), context)?
+                }
+            }
+        };
+        if items.is_empty() && rest.is_empty() {
+            return Err(ConvertError::TooLow);
+        }
+
+        let ret = items.iter().rev().fold(rest, |acc, (tu, x)| {
+            self.join_item(*tu, *x as u64, acc, context)
+        });
+        Ok(self.relate(ret, context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CivilCalendar::*;
+    use super::*;
+
+    #[test]
+    fn new_years() {
+        // 1 Muharram 1445, 1 Farvardin 1403, 1 Tishrei 5785 and 5786
+        assert_eq!(
+            gregorian_date(IslamicTabular.rata_die(1445, 1, 1)),
+            (2023, 7, 19)
+        );
+        assert_eq!(
+            gregorian_date(SolarHijri.rata_die(1403, 1, 1)),
+            (2024, 3, 20)
+        );
+        assert_eq!(
+            gregorian_date(SolarHijri.rata_die(1404, 1, 1)),
+            (2025, 3, 21)
+        );
+        assert_eq!(gregorian_date(Hebrew.rata_die(5785, 1, 1)), (2024, 10, 3));
+        assert_eq!(gregorian_date(Hebrew.rata_die(5786, 1, 1)), (2025, 9, 23));
+        assert_eq!(gregorian_date(1), (1, 1, 1));
+        assert_eq!(gregorian_date(UNIX_EPOCH_RD), (1970, 1, 1));
+    }
+
+    #[test]
+    fn roundtrip() {
+        for cal in [Gregorian, IslamicTabular, SolarHijri, Hebrew, ThaiBuddhist] {
+            let mut previous = cal.date(730_000 - 1);
+            for rd in 730_000..745_000 {
+                let (y, m, d) = cal.date(rd);
+                assert_eq!(cal.rata_die(y, m, d), rd, "{cal:?} {rd}");
+                assert!(d >= 1 && d <= cal.days_in_month(y, m), "{cal:?} {rd}");
+                assert!((y, m, d) > previous, "{cal:?} {rd}");
+                previous = (y, m, d);
+                #[cfg(feature = "chrono")]
+                assert_eq!(cal.month_at(cal.month_index(y, m)), (y, m));
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn formatting() {
+        let at = |cal: CivilCalendar, y, m, d| {
+            let rd = cal.rata_die(y, m, d) as i32;
+            NaiveDate::from_num_days_from_ce_opt(rd)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+        };
+        let mut f = Formatter::new();
+        f.num_items(2);
+        let (from, to) = (at(SolarHijri, 1402, 1, 1), at(SolarHijri, 1403, 1, 1));
+        assert_eq!(
+            f.convert_civil(Gregorian, from, to).unwrap(),
+            "11 months 4 weeks ago"
+        );
+        assert_eq!(f.convert_civil(SolarHijri, from, to).unwrap(), "1 year ago");
+        // Adar I and Adar II
+        let (from, to) = (at(Hebrew, 5784, 5, 1), at(Hebrew, 5784, 8, 1));
+        assert_eq!(f.convert_civil(Hebrew, from, to).unwrap(), "3 months ago");
+        let (from, to) = (at(Hebrew, 5784, 7, 10), at(Hebrew, 5785, 6, 10));
+        assert_eq!(f.convert_civil(Hebrew, from, to).unwrap(), "1 year ago");
+        let (from, to) = (at(Gregorian, 2024, 1, 31), at(Gregorian, 2024, 3, 1));
+        assert_eq!(
+            f.convert_civil(Gregorian, from, to).unwrap(),
+            "1 month 1 day ago"
+        );
+        assert_eq!(
+            f.convert_civil(ThaiBuddhist, from, to).unwrap(),
+            "1 month 1 day ago"
+        );
+        assert_eq!(
+            f.convert_civil(Gregorian, to, from),
+            Err(ConvertError::Negative)
+        );
+        f.chunking(Chunking::AdjacentWithZeros);
+        let (from, to) = (at(Gregorian, 2023, 1, 1), at(Gregorian, 2024, 1, 2));
+        assert_eq!(
+            f.convert_civil(Gregorian, from, to).unwrap(),
+            "1 year 0 months ago"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn relation() {
+        use super::super::Relation;
+        let at = |y, m, d| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+        };
+        let mut f = Formatter::new();
+        f.civil_calendar(Gregorian).relation(Relation::Overdue);
+        let (from, to) = (at(2024, 1, 31), at(2024, 3, 31));
+        assert_eq!(
+            f.convert_civil(Gregorian, from, to).unwrap(),
+            "overdue by 2 months"
+        );
+        f.relation(Relation::Before).reference("release");
+        assert_eq!(
+            f.convert_civil(Gregorian, from, to).unwrap(),
+            "2 months before release"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn calendar_model() {
+        use super::super::CalendarModel;
+        let at = |y, m, d| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+        };
+        let mut f = Formatter::new();
+        f.calendar(CalendarModel::THIRTY_DAY_MONTHS);
+        // 30 days are a month of the model, but not a whole calendar month
+        let (from, to) = (at(2024, 1, 1), at(2024, 1, 31));
+        assert_eq!(f.convert_civil(Gregorian, from, to).unwrap(), "4 weeks ago");
+    }
+}
@@ -0,0 +1,190 @@
+//! Clock-style formatting like "01:02:03" or "3d 04:05:06".
+
+use super::{split_up, English, Language, TimeUnit};
+use std::time::Duration;
+
+/// Formatter of durations as clock digits, like "01:02:03", "3d 04:05:06" or "1:02:03.250".
+/// Build it with new() and maybe modify some options, then use convert.
+///
+/// Only `Days`, `Hours`, `Minutes` and `Seconds` are used, the biggest one
+/// (limited by `max_unit`) takes as many digits as needed.
+/// ```
+/// use std::time::Duration;
+/// let c = timeago::Clock::new();
+/// assert_eq!(c.convert(Duration::from_secs(3723)), "01:02:03");
+/// assert_eq!(c.convert(Duration::from_secs(100 * 3600)), "100:00:00");
+/// ```
+pub struct Clock<L: Language = English> {
+    lang: L,
+    min_unit: TimeUnit,
+    max_unit: TimeUnit,
+    pad: bool,
+    fraction_digits: usize,
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock {
+    /// Constructor for English clock-style formatting
+    ///
+    /// It shows hours, minutes and seconds, with hours zero-padded to two digits.
+    pub fn new() -> Clock {
+        Clock::with_language(English)
+    }
+}
+
+impl<L: Language> Clock<L> {
+    /// Constructor with specified language instance, used for the day count and `convert_uptime`
+    pub fn with_language(l: L) -> Self {
+        Clock {
+            lang: l,
+            min_unit: TimeUnit::Seconds,
+            max_unit: TimeUnit::Hours,
+            pad: true,
+            fraction_digits: 0,
+        }
+    }
+
+    /// Set the biggest unit. `Days` adds a leading day count when it is not zero,
+    /// `Minutes` makes output like "125:03".
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::TimeUnit;
+    /// let mut c = timeago::Clock::new();
+    /// c.max_unit(TimeUnit::Days);
+    /// assert_eq!(c.convert(Duration::from_secs(3 * 86400 + 4 * 3600 + 5 * 60 + 6)), "3d 04:05:06");
+    /// assert_eq!(c.convert(Duration::from_secs(3723)), "01:02:03");
+    /// c.max_unit(TimeUnit::Minutes);
+    /// assert_eq!(c.convert(Duration::from_secs(125 * 60 + 3)), "125:03");
+    /// ```
+    pub fn max_unit(&mut self, x: TimeUnit) -> &mut Self {
+        self.max_unit = x;
+        self
+    }
+
+    /// Set the smallest unit. Smaller parts are truncated.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::TimeUnit;
+    /// let mut c = timeago::Clock::new();
+    /// c.min_unit(TimeUnit::Minutes);
+    /// assert_eq!(c.convert(Duration::from_secs(3723)), "01:02");
+    /// c.min_unit(TimeUnit::Days).max_unit(TimeUnit::Days);
+    /// assert_eq!(c.convert(Duration::from_secs(3723)), "0d");
+    /// ```
+    pub fn min_unit(&mut self, x: TimeUnit) -> &mut Self {
+        self.min_unit = x;
+        self
+    }
+
+    /// Whether to zero-pad the leading field to two digits. Default is true.
+    /// ```
+    /// use std::time::Duration;
+    /// let mut c = timeago::Clock::new();
+    /// c.pad(false);
+    /// assert_eq!(c.convert(Duration::from_secs(3723)), "1:02:03");
+    /// ```
+    pub fn pad(&mut self, x: bool) -> &mut Self {
+        self.pad = x;
+        self
+    }
+
+    /// Number of digits of fractional seconds to show (truncated), up to 9. Default is 0.
+    /// Only used when the smallest unit is `Seconds`. Bigger numbers show all 9 digits.
+    /// ```
+    /// use std::time::Duration;
+    /// let mut c = timeago::Clock::new();
+    /// c.fraction_digits(3);
+    /// assert_eq!(c.convert(Duration::from_millis(3723_256)), "01:02:03.256");
+    /// c.fraction_digits(12);
+    /// assert_eq!(c.convert(Duration::from_millis(3723_256)), "01:02:03.256000000");
+    /// ```
+    pub fn fraction_digits(&mut self, x: usize) -> &mut Self {
+        self.fraction_digits = x.min(9);
+        self
+    }
+
+    /// Units in use, from the biggest to the smallest
+    fn units(&self) -> Vec<TimeUnit> {
+        use TimeUnit::*;
+        let min = self.min_unit.max(Seconds).min(Days);
+        let max = self.max_unit.max(min).min(Days);
+        [Days, Hours, Minutes, Seconds]
+            .iter()
+            .copied()
+            .filter(|x| *x >= min && *x <= max)
+            .collect()
+    }
+
+    /// Format the duration as clock digits.
+    /// ```
+    /// # #[cfg(feature = "translations")] {
+    /// use std::time::Duration;
+    /// let mut c = timeago::Clock::with_language(timeago::languages::russian::Russian);
+    /// c.max_unit(timeago::TimeUnit::Days);
+    /// assert_eq!(c.convert(Duration::from_secs(3 * 86400 + 59)), "3 дн. 00:00:59");
+    /// c.min_unit(timeago::TimeUnit::Days);
+    /// assert_eq!(c.convert(Duration::from_secs(3600)), "0 дн.");
+    /// # }
+    /// ```
+    pub fn convert(&self, d: Duration) -> String {
+        let mut days = String::new();
+        let mut fields = Vec::new();
+        let mut rem = d;
+        let units = self.units();
+        let only_days = units == [TimeUnit::Days];
+        for tu in units {
+            let (x, r) = split_up(rem, tu);
+            // counts of seconds and bigger units always fit
+            let x = x as u64;
+            rem = r;
+            if tu == TimeUnit::Days {
+                if x > 0 || only_days {
+                    days = self.lang.short_days(x) + " ";
+                }
+            } else if fields.is_empty() && days.is_empty() && !self.pad {
+                fields.push(x.to_string());
+            } else {
+                fields.push(format!("{x:02}"));
+            }
+        }
+        let mut ret = days + &fields.join(":");
+        if self.min_unit <= TimeUnit::Seconds && self.fraction_digits > 0 {
+            let nanos = format!("{:09}", rem.subsec_nanos());
+            ret.push('.');
+            ret += &nanos[..self.fraction_digits];
+        }
+        ret.trim_end().to_owned()
+    }
+
+    /// Format the duration like `uptime` does after "up": "3 days, 4:05" or "4:05".
+    /// Minimal and maximal units are not taken into account here.
+    /// ```
+    /// use std::time::Duration;
+    /// let c = timeago::Clock::new();
+    /// assert_eq!(c.convert_uptime(Duration::from_secs(3 * 86400 + 4 * 3600 + 5 * 60 + 6)), "3 days, 4:05");
+    /// assert_eq!(c.convert_uptime(Duration::from_secs(86400 + 59 * 60)), "1 day, 0:59");
+    /// assert_eq!(c.convert_uptime(Duration::from_secs(3723)), "1:02");
+    /// ```
+    pub fn convert_uptime(&self, d: Duration) -> String {
+        let (days, rem) = split_up(d, TimeUnit::Days);
+        let days = days as u64;
+        let (hours, rem) = split_up(rem, TimeUnit::Hours);
+        let (minutes, _) = split_up(rem, TimeUnit::Minutes);
+        let time = format!("{hours}:{minutes:02}");
+        if days == 0 {
+            return time;
+        }
+        let word = self.lang.get_word(TimeUnit::Days, days);
+        let between = self.lang.between_value_and_word();
+        if self.lang.place_unit_before(days) {
+            format!("{word}{between}{days}, {time}")
+        } else {
+            format!("{days}{between}{word}, {time}")
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Compact machine-style durations used in configs and command lines, like "1h30m5s", "2days 3h" or "1h 30min".
+//!
+//! Months and years are converted using the same approximate lengths as the rest of the crate
+//! (about 30.4 days per month), not the slightly different ones of the original implementations.
+//!
+//! ```
+//! use std::time::Duration;
+//! use timeago::compact::{format, parse, Dialect};
+//! let d = Duration::from_secs(5400);
+//! assert_eq!(format(d, Dialect::Go), "1h30m0s");
+//! assert_eq!(format(d, Dialect::Humantime), "1h 30m");
+//! assert_eq!(format(d, Dialect::Systemd), "1h 30min");
+//! assert_eq!(parse("1h30m", Dialect::Go), Ok(d));
+//! assert_eq!(parse("90 minutes", Dialect::Humantime), Ok(d));
+//! assert_eq!(parse("1.5h", Dialect::Systemd), Ok(d));
+//! ```
+
+use super::parse::{nanos_to_duration, number, unexpected, ParseError, ParseErrorKind};
+use super::TimeUnit;
+use std::time::Duration;
+
+/// Flavour of the compact duration syntax
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Dialect {
+    /// Go's `time.Duration`: "1h30m5s", "1.5s", "300ms". Units from `ns` to `h`, fractions allowed.
+    Go,
+    /// Rust's `humantime` crate: "2days 3h 4m", "1year 2months". Integer amounts only.
+    Humantime,
+    /// systemd.time(7) time spans: "1h 30min", "2w 1d", "1.5s". A bare number means seconds.
+    Systemd,
+}
+
+/// Unit names accepted by the parsers
+fn unit_by_name(name: &str, dialect: Dialect) -> Option<TimeUnit> {
+    use TimeUnit::*;
+    Some(match (dialect, name) {
+        (_, "ns") => Nanoseconds,
+        (_, "us") | (_, "µs") | (_, "μs") => Microseconds,
+        (_, "ms") => Milliseconds,
+        (_, "s") => Seconds,
+        (_, "m") => Minutes,
+        (_, "h") => Hours,
+        (Dialect::Go, _) => return None,
+        (_, "nsec") => Nanoseconds,
+        (_, "usec") => Microseconds,
+        (_, "msec") => Milliseconds,
+        (_, "seconds") | (_, "second") | (_, "sec") => Seconds,
+        (_, "minutes") | (_, "minute") | (_, "min") => Minutes,
+        (_, "hours") | (_, "hour") | (_, "hr") => Hours,
+        (_, "days") | (_, "day") | (_, "d") => Days,
+        (_, "weeks") | (_, "week") | (_, "w") => Weeks,
+        (_, "months") | (_, "month") | (_, "M") => Months,
+        (_, "years") | (_, "year") | (_, "y") => Years,
+        _ => return None,
+    })
+}
+
+/// Name of a unit used by the formatters, depending on the amount
+fn unit_name(tu: TimeUnit, dialect: Dialect, x: u128) -> &'static str {
+    use TimeUnit::*;
+    match (dialect, tu) {
+        (_, Nanoseconds) => "ns",
+        (Dialect::Go, Microseconds) => "µs",
+        (_, Microseconds) => "us",
+        (_, Milliseconds) => "ms",
+        (_, Seconds) => "s",
+        (Dialect::Systemd, Minutes) => "min",
+        (_, Minutes) => "m",
+        (_, Hours) => "h",
+        (Dialect::Systemd, Days) => "d",
+        (Dialect::Systemd, Weeks) => "w",
+        (Dialect::Systemd, Months) => "month",
+        (Dialect::Systemd, _) => "y",
+        (_, Days) if x == 1 => "day",
+        (_, Days) => "days",
+        (_, Weeks) if x == 1 => "week",
+        (_, Weeks) => "weeks",
+        (_, Months) if x == 1 => "month",
+        (_, Months) => "months",
+        (_, _) if x == 1 => "year",
+        (_, _) => "years",
+    }
+}
+
+/// Digits after the decimal point of `rem` parts of `unit`, without trailing zeros
+fn fraction(rem: u128, unit: u128) -> String {
+    let digits = unit.to_string().len() - 1;
+    let s = format!("{rem:0digits$}");
+    s.trim_end_matches('0').to_owned()
+}
+
+fn format_go(d: Duration) -> String {
+    use TimeUnit::*;
+    let n = d.as_nanos();
+    if n == 0 {
+        return "0s".to_owned();
+    }
+    if n < 1_000_000_000 {
+        // Go uses the biggest sub-second unit and a fraction
+        let tu = [Milliseconds, Microseconds, Nanoseconds]
+            .iter()
+            .copied()
+            .find(|x| x.min_duration().as_nanos() <= n)
+            .unwrap();
+        let unit = tu.min_duration().as_nanos();
+        let frac = fraction(n % unit, unit);
+        let name = unit_name(tu, Dialect::Go, 0);
+        return if frac.is_empty() {
+            format!("{}{name}", n / unit)
+        } else {
+            format!("{}.{frac}{name}", n / unit)
+        };
+    }
+    let mut ret = String::new();
+    let (h, rem) = (n / 3_600_000_000_000, n % 3_600_000_000_000);
+    let (m, rem) = (rem / 60_000_000_000, rem % 60_000_000_000);
+    if h > 0 {
+        ret += &format!("{h}h");
+    }
+    if h > 0 || m > 0 {
+        ret += &format!("{m}m");
+    }
+    let frac = fraction(rem % 1_000_000_000, 1_000_000_000);
+    ret += &(rem / 1_000_000_000).to_string();
+    if !frac.is_empty() {
+        ret.push('.');
+        ret += &frac;
+    }
+    ret.push('s');
+    ret
+}
+
+/// Format the duration in the given dialect.
+///
+/// Go format always shows seconds when the duration is at least one second, like Go does ("1h0m0s").
+/// Other dialects emit non-zero units separated by spaces.
+/// systemd format does not go below microseconds.
+/// ```
+/// use std::time::Duration;
+/// use timeago::compact::{format, Dialect};
+/// assert_eq!(format(Duration::from_millis(1500), Dialect::Go), "1.5s");
+/// assert_eq!(format(Duration::from_micros(1500), Dialect::Go), "1.5ms");
+/// assert_eq!(format(Duration::from_secs(3600), Dialect::Go), "1h0m0s");
+/// assert_eq!(format(Duration::from_secs(2 * 86400 + 3 * 3600), Dialect::Humantime), "2days 3h");
+/// assert_eq!(format(Duration::from_secs(86400 + 7), Dialect::Humantime), "1day 7s");
+/// assert_eq!(format(Duration::from_secs(15 * 86400 + 60), Dialect::Systemd), "2w 1d 1min");
+/// assert_eq!(format(Duration::from_nanos(1_000_500), Dialect::Systemd), "1ms");
+/// assert_eq!(format(Duration::from_secs(0), Dialect::Systemd), "0");
+/// ```
+pub fn format(d: Duration, dialect: Dialect) -> String {
+    use TimeUnit::*;
+    let units: &[TimeUnit] = match dialect {
+        Dialect::Go => return format_go(d),
+        Dialect::Humantime => &[
+            Years,
+            Months,
+            Days,
+            Hours,
+            Minutes,
+            Seconds,
+            Milliseconds,
+            Microseconds,
+            Nanoseconds,
+        ],
+        Dialect::Systemd => &[
+            Years,
+            Months,
+            Weeks,
+            Days,
+            Hours,
+            Minutes,
+            Seconds,
+            Milliseconds,
+            Microseconds,
+        ],
+    };
+    let mut items = Vec::new();
+    let mut rem = d.as_nanos();
+    for tu in units {
+        let unit = tu.min_duration().as_nanos();
+        let x = rem / unit;
+        rem %= unit;
+        if x > 0 {
+            items.push(format!("{x}{}", unit_name(*tu, dialect, x)));
+        }
+    }
+    if items.is_empty() {
+        return match dialect {
+            Dialect::Systemd => "0".to_owned(),
+            _ => "0s".to_owned(),
+        };
+    }
+    items.join(" ")
+}
+
+/// Parse a duration in the given dialect.
+///
+/// Go syntax has no spaces and only accepts bare "0" without a unit.
+/// Humantime and systemd syntaxes allow spaces between and within components,
+/// accept long unit names like "minutes" and units up to years. Only systemd and Go allow fractions.
+/// ```
+/// use std::time::Duration;
+/// use timeago::compact::{parse, Dialect};
+/// use timeago::ParseErrorKind;
+/// assert_eq!(parse("1h15m30.5s", Dialect::Go), Ok(Duration::from_millis(4530_500)));
+/// assert_eq!(parse("2days 3h", Dialect::Humantime), Ok(Duration::from_secs(2 * 86400 + 3 * 3600)));
+/// assert_eq!(parse("1w 2d", Dialect::Systemd), Ok(Duration::from_secs(9 * 86400)));
+/// assert_eq!(parse("90", Dialect::Systemd), Ok(Duration::from_secs(90)));
+/// assert_eq!(parse("1M", Dialect::Humantime), Ok(Duration::from_secs(2_628_003)));
+/// let e = parse("1h 30m", Dialect::Go).unwrap_err();
+/// assert_eq!((e.position(), e.kind()), (2, &ParseErrorKind::UnexpectedChar(' ')));
+/// let e = parse("5 fortnights", Dialect::Systemd).unwrap_err();
+/// assert_eq!((e.position(), e.kind()), (2, &ParseErrorKind::UnknownUnit("fortnights".to_owned())));
+/// ```
+pub fn parse(s: &str, dialect: Dialect) -> Result<Duration, ParseError> {
+    let spaces = dialect != Dialect::Go;
+    let separators: &[u8] = match dialect {
+        Dialect::Humantime => b"",
+        _ => b".",
+    };
+    let skip_spaces = |mut i: usize| {
+        while spaces && s[i..].starts_with(|c: char| c.is_whitespace()) {
+            i += s[i..].chars().next().unwrap().len_utf8();
+        }
+        i
+    };
+
+    let mut i = skip_spaces(0);
+    match s[i..].chars().next() {
+        Some('-') => return Err(ParseError::new(i, ParseErrorKind::Negative)),
+        Some('+') if dialect == Dialect::Go => i += 1,
+        _ => (),
+    }
+    if dialect == Dialect::Go && &s[i..] == "0" {
+        return Ok(Duration::new(0, 0));
+    }
+
+    let mut total: u128 = 0;
+    let mut ret = Duration::new(0, 0);
+    let mut first = true;
+    loop {
+        if i == s.len() && !first {
+            break;
+        }
+        let start = i;
+        let (n, j) = match number(s, i, separators)? {
+            Some(x) => x,
+            None => return Err(unexpected(s, i)),
+        };
+        i = skip_spaces(j);
+        let name_len = s[i..]
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(s.len() - i);
+        let name = &s[i..i + name_len];
+        let tu = if name.is_empty() {
+            if dialect == Dialect::Systemd && skip_spaces(i) == s.len() {
+                TimeUnit::Seconds
+            } else {
+                return Err(unexpected(s, i));
+            }
+        } else {
+            match unit_by_name(name, dialect) {
+                Some(x) => x,
+                None => {
+                    let u = name.to_owned();
+                    return Err(ParseError::new(i, ParseErrorKind::UnknownUnit(u)));
+                }
+            }
+        };
+        total = n
+            .nanos(tu.min_duration().as_nanos())
+            .and_then(|x| total.checked_add(x))
+            .ok_or_else(|| ParseError::new(start, ParseErrorKind::Overflow))?;
+        ret = nanos_to_duration(total, start)?;
+        i = skip_spaces(i + name_len);
+        first = false;
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for d in [
+            Duration::new(0, 0),
+            Duration::new(0, 1),
+            Duration::new(0, 1_500),
+            Duration::new(59, 999_999_999),
+            Duration::new(3600, 0),
+            Duration::new(100_000_000, 123_456_789),
+        ] {
+            assert_eq!(parse(&format(d, Dialect::Go), Dialect::Go), Ok(d));
+            assert_eq!(
+                parse(&format(d, Dialect::Humantime), Dialect::Humantime),
+                Ok(d)
+            );
+            let d_us = Duration::new(d.as_secs(), d.subsec_micros() * 1000);
+            assert_eq!(
+                parse(&format(d, Dialect::Systemd), Dialect::Systemd),
+                Ok(d_us)
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        use ParseErrorKind::*;
+        let e = |s: &str, dialect| {
+            let e = parse(s, dialect).unwrap_err();
+            (e.position(), e.kind().clone())
+        };
+        assert_eq!(e("", Dialect::Go), (0, UnexpectedEnd));
+        assert_eq!(e("5", Dialect::Go), (1, UnexpectedEnd));
+        assert_eq!(e("-5s", Dialect::Go), (0, Negative));
+        assert_eq!(e("1d", Dialect::Go), (1, UnknownUnit("d".to_owned())));
+        assert_eq!(e("1.5h", Dialect::Humantime), (1, UnexpectedChar('.')));
+        assert_eq!(e("1h x", Dialect::Systemd), (3, UnexpectedChar('x')));
+        assert_eq!(e("1h 5", Dialect::Humantime), (4, UnexpectedEnd));
+        assert_eq!(e("99999999999999999999y", Dialect::Systemd), (0, Overflow));
+        assert_eq!(
+            e("1m 18446744073709551615s", Dialect::Systemd),
+            (3, Overflow)
+        );
+    }
+}
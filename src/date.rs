@@ -0,0 +1,88 @@
+//! Date-only inputs like birthdays and due dates, counted in whole calendar days.
+
+use super::{CivilCalendar, ConvertError, Formatter, Language, Relation, TimeUnit};
+use chrono::{NaiveDate, NaiveTime};
+
+impl<L: Language> Formatter<L> {
+    /// Format the span between two dates like "3 days ago", counting whole calendar days,
+    /// weeks, months and years, so that there is no time of day or DST transition to skew it.
+    /// The same date gives "today" or its translation, or zero days like "due in 0 days"
+    /// with relations other than `Ago` and `In`.
+    ///
+    /// Months and years are counted in the civil calendar if one is set, otherwise
+    /// in the Gregorian calendar. `from` should come before `to`, otherwise `"???"` will be returned.
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::NaiveDate;
+    /// use timeago::Relation;
+    /// let mut f = timeago::Formatter::new();
+    /// let d = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    /// let today = d(2024, 3, 31);
+    /// assert_eq!(f.convert_naive_date(d(2024, 3, 31), today), "today");
+    /// assert_eq!(f.convert_naive_date(d(2024, 3, 28), today), "3 days ago");
+    /// assert_eq!(f.convert_naive_date(d(2024, 3, 10), today), "3 weeks ago");
+    /// assert_eq!(f.convert_naive_date(d(2024, 2, 29), today), "1 month ago");
+    /// assert_eq!(f.convert_naive_date(d(1990, 4, 1), today), "33 years ago");
+    /// assert_eq!(f.convert_naive_date(d(2024, 4, 1), today), "???");
+    /// f.num_items(2);
+    /// assert_eq!(f.convert_naive_date(d(2023, 12, 25), today), "3 months 6 days ago");
+    /// f.num_items(1).relation(Relation::DueIn);
+    /// assert_eq!(f.convert_naive_date(today, d(2024, 4, 2)), "due in 2 days");
+    /// assert_eq!(f.convert_naive_date(today, today), "due in 0 days");
+    /// f.relation(Relation::Before).reference("release");
+    /// assert_eq!(f.convert_naive_date(today, today), "0 days before release");
+    /// ```
+    pub fn convert_naive_date(&self, from: NaiveDate, to: NaiveDate) -> String {
+        match self.try_convert_naive_date(from, to) {
+            Ok(x) => x,
+            Err(ConvertError::Negative) => "???".to_owned(),
+            Err(e) => self.convert_error(e),
+        }
+    }
+
+    /// Like `convert_naive_date`, but returning errors instead of `"???"` or "old".
+    ///
+    /// Requires `chrono` Cargo feature.
+    pub fn try_convert_naive_date(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<String, ConvertError> {
+        if from == to {
+            return Ok(match self.relation {
+                Relation::Ago | Relation::In => self.lang.today().to_owned(),
+                _ => {
+                    let context = self.context(None);
+                    let days = self.join_item(TimeUnit::Days, 0, String::new(), context);
+                    self.relate(days, context)
+                }
+            });
+        }
+        let cal = self.civil_calendar.unwrap_or(CivilCalendar::Gregorian);
+        let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        self.convert_civil(cal, from.and_time(midnight), to.and_time(midnight))
+    }
+}
+
+#[cfg(feature = "translations")]
+#[test]
+fn test_russian() {
+    use super::languages::russian::Russian;
+    let f = Formatter::with_language(Russian);
+    let d = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(
+        f.convert_naive_date(d(2024, 3, 9), d(2024, 3, 9)),
+        "сегодня"
+    );
+    assert_eq!(
+        f.convert_naive_date(d(2024, 3, 8), d(2024, 3, 9)),
+        "1 день назад"
+    );
+    assert_eq!(
+        f.convert_naive_date(d(2022, 3, 9), d(2024, 3, 9)),
+        "2 года назад"
+    );
+}
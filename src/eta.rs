@@ -0,0 +1,134 @@
+//! Estimated time left for long-running jobs, like "about 3 minutes remaining".
+
+use super::{English, Formatter, Language, Qualifier, Relation, WordContext};
+use std::time::{Duration, Instant};
+
+/// Estimator of the time left for a job from its progress, with the rate of progress smoothed
+/// by exponential moving average, formatting the estimate with the specified `Formatter`.
+/// ```
+/// use std::time::{Duration, Instant};
+/// let start = Instant::now();
+/// let secs = |x| start + Duration::from_secs(x);
+/// let mut eta = timeago::Eta::new(start);
+/// assert_eq!(eta.convert(), None);
+/// eta.update(0.1, secs(10));
+/// assert_eq!(eta.remaining(), Some(Duration::from_secs(90)));
+/// assert_eq!(eta.convert().unwrap(), "about 1 minute remaining");
+/// eta.update_items(50, 100, secs(50));
+/// assert_eq!(eta.convert().unwrap(), "about 50 seconds remaining");
+/// // the rate doubles, but the estimate follows it gradually
+/// eta.update_items(60, 100, secs(55));
+/// assert_eq!(eta.convert().unwrap(), "about 30 seconds remaining");
+/// eta.update(1.0, secs(60));
+/// assert_eq!(eta.convert().unwrap(), "now");
+/// ```
+pub struct Eta<L: Language = English> {
+    formatter: Formatter<L>,
+    smoothing: f64,
+    approximate: bool,
+    /// Smoothed progress per second
+    rate: Option<f64>,
+    /// Progress and time of the last measurement of the rate
+    last: (f64, Instant),
+    fraction: f64,
+}
+
+impl Eta {
+    /// Constructor for the job started at `start`, formatting in English
+    pub fn new(start: Instant) -> Eta {
+        Eta::with_formatter(Formatter::new(), start)
+    }
+}
+
+impl<L: Language> Eta<L> {
+    /// Constructor for the job started at `start`, formatting with the specified formatter
+    pub fn with_formatter(formatter: Formatter<L>, start: Instant) -> Self {
+        Eta {
+            formatter,
+            smoothing: 0.3,
+            approximate: true,
+            rate: None,
+            last: (0.0, start),
+            fraction: 0.0,
+        }
+    }
+
+    /// Access the formatter to change its settings
+    pub fn formatter(&mut self) -> &mut Formatter<L> {
+        &mut self.formatter
+    }
+
+    /// Set weight of the newest rate measurement, more than 0 and up to 1.
+    /// Lower values follow changes of the rate slower, 1 disables smoothing.
+    /// Default is 0.3.
+    pub fn smoothing(&mut self, x: f64) -> &mut Self {
+        assert!(x > 0.0 && x <= 1.0);
+        self.smoothing = x;
+        self
+    }
+
+    /// Set whether to mark the estimate as approximate, like "about 3 minutes remaining".
+    /// Default is true.
+    pub fn approximate(&mut self, x: bool) -> &mut Self {
+        self.approximate = x;
+        self
+    }
+
+    /// Report the progress from 0 to 1 reached at `now`
+    pub fn update(&mut self, fraction: f64, now: Instant) {
+        self.fraction = fraction.clamp(0.0, 1.0);
+        let (prev, since) = self.last;
+        let secs = now.saturating_duration_since(since).as_secs_f64();
+        if secs <= 0.0 {
+            return;
+        }
+        let sample = (self.fraction - prev) / secs;
+        self.rate = Some(match self.rate {
+            Some(rate) => rate + self.smoothing * (sample - rate),
+            None => sample,
+        });
+        self.last = (self.fraction, now);
+    }
+
+    /// Report the progress as `done` items out of `total` reached at `now`
+    pub fn update_items(&mut self, done: u64, total: u64, now: Instant) {
+        let fraction = if total == 0 {
+            1.0
+        } else {
+            done as f64 / total as f64
+        };
+        self.update(fraction, now);
+    }
+
+    /// Estimated time left, unknown until some progress is made
+    pub fn remaining(&self) -> Option<Duration> {
+        if self.fraction >= 1.0 {
+            return Some(Duration::new(0, 0));
+        }
+        let rate = self.rate.filter(|x| *x > 0.0)?;
+        let secs = (1.0 - self.fraction) / rate;
+        if secs.is_finite() && secs < u64::MAX as f64 {
+            Some(Duration::from_secs_f64(secs))
+        } else {
+            None
+        }
+    }
+
+    /// Format the estimated time left like "about 3 minutes remaining".
+    /// Times shorter than the minimal unit give the `too_low` string like "now".
+    pub fn convert(&self) -> Option<String> {
+        let context = WordContext {
+            relation: Relation::Remaining,
+            qualifier: if self.approximate {
+                Some(Qualifier::About)
+            } else {
+                None
+            },
+        };
+        let d = self.remaining()?;
+        Some(match self.formatter.try_convert_in(d, context) {
+            Ok(x) => x,
+            Err(e) => self.formatter.convert_error(e),
+        })
+    }
+}
@@ -0,0 +1,87 @@
+//! Idiomatic fractions of units like "half an hour ago" or "полтора года назад".
+
+use super::{part_of, Formatter, Language, TimeUnit};
+use std::ops::Range;
+use std::time::Duration;
+
+/// Fraction of a unit expressed with a dedicated phrase, see `Language::fraction`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Fraction {
+    /// Like "a quarter of an hour"
+    Quarter,
+    /// Like "half an hour" or "an hour and a half"
+    Half,
+}
+
+impl Fraction {
+    /// Parts of the unit of length `len` close enough to the fraction to be phrased with it
+    pub(crate) fn window(self, len: Duration) -> Range<Duration> {
+        let twentieths = match self {
+            Fraction::Quarter => 5,
+            Fraction::Half => 10,
+        };
+        part_of(len, twentieths - TOLERANCE, 20)..part_of(len, twentieths + TOLERANCE, 20)
+    }
+}
+
+impl<L: Language> Formatter<L> {
+    /// Phrase for the final item `x` units `tu` with the dropped remainder `rem`,
+    /// if the language has one for the fraction the duration is close to.
+    /// For the only item, fractions of the bigger unit like "half a year" are also considered.
+    pub(crate) fn fraction_item(
+        &self,
+        tu: TimeUnit,
+        x: u64,
+        rem: Duration,
+        only: bool,
+    ) -> Option<String> {
+        let near = |part: Duration, len: Duration, f: Fraction| f.window(len).contains(&part);
+        let len = self.calendar.unit_length(tu);
+        if near(rem, len, Fraction::Half) {
+            if let Some(phrase) = self.lang.fraction(tu, x, Fraction::Half) {
+                return Some(phrase);
+            }
+        }
+        if !only {
+            return None;
+        }
+        let bigger = self.bigger_unit(tu).filter(|u| *u <= self.max_unit)?;
+        let whole = len * u32::try_from(x).ok()? + rem;
+        let bigger_len = self.calendar.unit_length(bigger);
+        [Fraction::Half, Fraction::Quarter]
+            .iter()
+            .filter(|f| near(whole, bigger_len, **f))
+            .find_map(|f| self.lang.fraction(bigger, 0, *f))
+    }
+}
+
+/// How far from the exact fraction of the unit the duration can be, in twentieths of the unit
+const TOLERANCE: u128 = 1;
+
+#[cfg(feature = "translations")]
+#[test]
+fn test_languages() {
+    use super::languages::{
+        chinese::Chinese, german::German, japanese::Japanese, russian::Russian,
+    };
+    let m = |x: u64| Duration::from_secs(x * 60);
+    let mut f = Formatter::with_language(German);
+    f.fractions(true);
+    assert_eq!(f.convert(m(30)), "vor einer halben Stunde");
+    assert_eq!(f.convert(m(90)), "vor anderthalb Stunden");
+    assert_eq!(f.convert(m(150)), "vor 2 Stunden");
+    let mut f = Formatter::with_language(Russian);
+    f.fractions(true);
+    assert_eq!(f.convert(m(30)), "полчаса назад");
+    assert_eq!(f.convert(m(15)), "четверть часа назад");
+    assert_eq!(f.convert(m(90)), "полтора часа назад");
+    assert_eq!(f.convert(m(183 * 1440)), "полгода назад");
+    let mut f = Formatter::with_language(Japanese);
+    f.fractions(true);
+    assert_eq!(f.convert(m(183 * 1440)), "半年前");
+    assert_eq!(f.convert(m(150)), "2時間半前");
+    let mut f = Formatter::with_language(Chinese);
+    f.fractions(true);
+    assert_eq!(f.convert(m(90)), "1个半小时 之前");
+}
@@ -0,0 +1,302 @@
+//! HTML `<time>` elements like `<time datetime="PT3H" lang="en" dir="ltr">3 hours ago</time>`.
+
+use super::{iso8601, English, Formatter, Language, Qualifier, WordContext};
+use std::ops::Range;
+use std::time::Duration;
+
+/// Number and unit word of an item like "3 hours", as byte ranges of the formatted text
+struct Item {
+    number: Range<usize>,
+    word: Range<usize>,
+}
+
+/// Escape text for use in HTML content or a quoted attribute value
+fn escape(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => ret.push_str("&amp;"),
+            '<' => ret.push_str("&lt;"),
+            '>' => ret.push_str("&gt;"),
+            '"' => ret.push_str("&quot;"),
+            '\'' => ret.push_str("&#39;"),
+            c => ret.push(c),
+        }
+    }
+    ret
+}
+
+/// Renderer of `Formatter` output as an HTML `<time>` element, with escaping,
+/// machine-readable `datetime` attribute and `lang` and `dir` attributes taken from the language.
+///
+/// Requires `html` Cargo feature.
+/// ```
+/// use std::time::Duration;
+/// let mut h = timeago::HtmlFormatter::new();
+/// h.class("ago").number_class("num");
+/// assert_eq!(
+///     h.convert(Duration::from_secs(3 * 3600)),
+///     r#"<time datetime="PT3H" lang="en" dir="ltr" class="ago"><span class="num">3</span> hours ago</time>"#,
+/// );
+/// h.formatter().ago("<later>");
+/// assert_eq!(
+///     h.convert(Duration::from_secs(60)),
+///     r#"<time datetime="PT1M" lang="en" dir="ltr" class="ago"><span class="num">1</span> minute &lt;later&gt;</time>"#,
+/// );
+/// ```
+pub struct HtmlFormatter<L: Language = English> {
+    formatter: Formatter<L>,
+    class: Option<String>,
+    number_class: Option<String>,
+    unit_class: Option<String>,
+}
+
+impl Default for HtmlFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HtmlFormatter {
+    /// Constructor with the default English `Formatter`
+    pub fn new() -> HtmlFormatter {
+        HtmlFormatter::with_formatter(Formatter::new())
+    }
+}
+
+impl<L: Language> HtmlFormatter<L> {
+    /// Constructor using the specified formatter for the text
+    pub fn with_formatter(f: Formatter<L>) -> Self {
+        HtmlFormatter {
+            formatter: f,
+            class: None,
+            number_class: None,
+            unit_class: None,
+        }
+    }
+
+    /// Access the formatter to change its settings
+    pub fn formatter(&mut self) -> &mut Formatter<L> {
+        &mut self.formatter
+    }
+
+    /// Set `class` attribute of the `<time>` element
+    pub fn class(&mut self, x: &str) -> &mut Self {
+        self.class = Some(x.to_owned());
+        self
+    }
+
+    /// Wrap numbers into `<span>` elements with the given class
+    pub fn number_class(&mut self, x: &str) -> &mut Self {
+        self.number_class = Some(x.to_owned());
+        self
+    }
+
+    /// Wrap unit words into `<span>` elements with the given class
+    /// ```
+    /// use std::time::Duration;
+    /// let mut h = timeago::HtmlFormatter::new();
+    /// h.unit_class("unit").formatter().num_items(2);
+    /// assert_eq!(
+    ///     h.convert(Duration::from_secs(3660)),
+    ///     r#"<time datetime="PT1H1M" lang="en" dir="ltr">1 <span class="unit">hour</span> 1 <span class="unit">minute</span> ago</time>"#,
+    /// );
+    /// ```
+    pub fn unit_class(&mut self, x: &str) -> &mut Self {
+        self.unit_class = Some(x.to_owned());
+        self
+    }
+
+    /// Format the duration like `Formatter::convert` in a `<time>` element
+    /// with ISO 8601 duration as the `datetime` attribute, rounded to milliseconds.
+    /// ```
+    /// use std::time::Duration;
+    /// let h = timeago::HtmlFormatter::new();
+    /// assert_eq!(
+    ///     h.convert(Duration::new(2, 345_678_901)),
+    ///     r#"<time datetime="PT2.346S" lang="en" dir="ltr">2 seconds ago</time>"#,
+    /// );
+    /// assert_eq!(
+    ///     h.convert(Duration::from_micros(1_999_600)),
+    ///     r#"<time datetime="PT2S" lang="en" dir="ltr">1 second ago</time>"#,
+    /// );
+    /// ```
+    pub fn convert(&self, d: Duration) -> String {
+        let millis = (d.subsec_nanos() + 500_000) / 1_000_000;
+        let datetime =
+            Duration::new(d.as_secs(), 0).saturating_add(Duration::from_millis(u64::from(millis)));
+        self.element(&iso8601::format(datetime), None, &self.formatter.convert(d))
+    }
+
+    /// Format the timespan like `Formatter::convert_chrono` in a `<time>` element
+    /// with the moment `from` as the `datetime` attribute and its absolute date and time as `title`.
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::{TimeZone, Utc};
+    /// let h = timeago::HtmlFormatter::new();
+    /// let now = Utc.with_ymd_and_hms(2024, 3, 5, 14, 0, 0).unwrap();
+    /// let from = Utc.with_ymd_and_hms(2024, 3, 5, 11, 5, 0).unwrap();
+    /// assert_eq!(
+    ///     h.convert_chrono(from, now),
+    ///     r#"<time datetime="2024-03-05T11:05:00Z" title="Mar 5, 2024 at 11:05" lang="en" dir="ltr">2 hours ago</time>"#,
+    /// );
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn convert_chrono<Tz1, Tz2>(
+        &self,
+        from: chrono::DateTime<Tz1>,
+        to: chrono::DateTime<Tz2>,
+    ) -> String
+    where
+        Tz1: chrono::TimeZone,
+        Tz2: chrono::TimeZone,
+        Tz1::Offset: std::fmt::Display,
+    {
+        use chrono::{Datelike, Timelike};
+        let lang = &self.formatter.lang;
+        let date = lang.absolute_date(from.day(), from.month(), Some(from.year()));
+        let time = format!("{:02}:{:02}", from.hour(), from.minute());
+        let title = lang.day_at_time(&date, &time);
+        let datetime = from.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true);
+        let text = self.formatter.convert_chrono(from, to);
+        self.element(&datetime, Some(&title), &text)
+    }
+
+    /// Find the items in the text formatted by the formatter, looking for numbers
+    /// next to the unit words it uses
+    fn items(&self, text: &str) -> Vec<Item> {
+        use Qualifier::*;
+        let f = &self.formatter;
+        let qualifiers = [None, Some(About), Some(Over), Some(Almost), Some(LessThan)];
+        let contexts: Vec<WordContext> = qualifiers
+            .iter()
+            .chain(&[Some(Between)])
+            .map(|q| f.context(*q))
+            .chain(Some(WordContext::AGO))
+            .collect();
+        let between = f.lang.between_value_and_word();
+        let mut ret = Vec::new();
+        let mut pos = 0;
+        while let Some(start) = text[pos..].find(|c: char| c.is_ascii_digit()) {
+            let start = pos + start;
+            pos = text[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(text.len(), |len| start + len);
+            let x = match text[start..pos].parse() {
+                Ok(x) => x,
+                Err(_) => continue,
+            };
+            let words = f
+                .units()
+                .flat_map(|tu| contexts.iter().map(move |c| f.get_word(tu, x, *c)))
+                .filter(|word| !word.is_empty());
+            let word = if f.lang.place_unit_before(x) {
+                text[..start].strip_suffix(between).and_then(|before| {
+                    let word = words
+                        .filter(|w| before.ends_with(w))
+                        .max_by_key(|w| w.len())?;
+                    Some(before.len() - word.len()..before.len())
+                })
+            } else {
+                text[pos..].strip_prefix(between).and_then(|after| {
+                    let word = words
+                        .filter(|w| after.starts_with(w))
+                        .max_by_key(|w| w.len())?;
+                    let word_start = pos + between.len();
+                    Some(word_start..word_start + word.len())
+                })
+            };
+            if let Some(word) = word {
+                ret.push(Item {
+                    number: start..pos,
+                    word,
+                });
+            }
+        }
+        ret
+    }
+
+    fn element(&self, datetime: &str, title: Option<&str>, text: &str) -> String {
+        let lang = &self.formatter.lang;
+        let mut ret = format!("<time datetime=\"{}\"", escape(datetime));
+        if let Some(title) = title {
+            ret += &format!(" title=\"{}\"", escape(title));
+        }
+        let dir = if lang.right_to_left() { "rtl" } else { "ltr" };
+        ret += &format!(" lang=\"{}\" dir=\"{dir}\"", escape(lang.language_tag()));
+        if let Some(ref class) = self.class {
+            ret += &format!(" class=\"{}\"", escape(class));
+        }
+        ret.push('>');
+
+        if self.number_class.is_none() && self.unit_class.is_none() {
+            return ret + &escape(text) + "</time>";
+        }
+        let span = |class: &Option<String>| match class {
+            Some(c) => (format!("<span class=\"{}\">", escape(c)), "</span>"),
+            None => (String::new(), ""),
+        };
+        let (number, unit) = (span(&self.number_class), span(&self.unit_class));
+        let mut parts: Vec<_> = self
+            .items(text)
+            .into_iter()
+            .flat_map(|item| [(item.number, &number), (item.word, &unit)])
+            .collect();
+        parts.sort_by_key(|(range, _)| range.start);
+        let mut pos = 0;
+        for (range, (start, end)) in parts {
+            ret += &escape(&text[pos..range.start]);
+            ret += start;
+            ret += &escape(&text[range.clone()]);
+            ret += end;
+            pos = range.end;
+        }
+        ret += &escape(&text[pos..]);
+        ret + "</time>"
+    }
+}
+
+#[test]
+fn test_escaping() {
+    let mut h = HtmlFormatter::new();
+    h.class("a\"b")
+        .unit_class("<u>")
+        .formatter()
+        .too_low("<now> & 'then'");
+    assert_eq!(
+        h.convert(Duration::from_secs(0)),
+        "<time datetime=\"PT0S\" lang=\"en\" dir=\"ltr\" class=\"a&quot;b\">&lt;now&gt; &amp; &#39;then&#39;</time>"
+    );
+    assert_eq!(
+        h.convert(Duration::from_secs(2)),
+        "<time datetime=\"PT2S\" lang=\"en\" dir=\"ltr\" class=\"a&quot;b\">2 <span class=\"&lt;u&gt;\">seconds</span> ago</time>"
+    );
+}
+
+#[cfg(feature = "translations")]
+#[test]
+fn test_languages() {
+    use super::languages::{basque::Basque, japanese::Japanese, russian::Russian};
+    let mut h = HtmlFormatter::with_formatter(Formatter::with_language(Russian));
+    h.number_class("n").unit_class("u");
+    h.formatter().num_items(2).ago("назад 2");
+    assert_eq!(
+        h.convert(Duration::from_secs(5 * 3600 + 60)),
+        "<time datetime=\"PT5H1M\" lang=\"ru\" dir=\"ltr\"><span class=\"n\">5</span> <span class=\"u\">часов</span> <span class=\"n\">1</span> <span class=\"u\">минуту</span> назад 2</time>"
+    );
+    let mut h = HtmlFormatter::with_formatter(Formatter::with_language(Japanese));
+    h.unit_class("u");
+    assert_eq!(
+        h.convert(Duration::from_secs(3 * 3600)),
+        "<time datetime=\"PT3H\" lang=\"ja\" dir=\"ltr\">3<span class=\"u\">時間</span>前</time>"
+    );
+    let mut h = HtmlFormatter::with_formatter(Formatter::with_language(Basque));
+    h.number_class("n").unit_class("u");
+    assert_eq!(
+        h.convert(Duration::from_secs(3600)),
+        "<time datetime=\"PT1H\" lang=\"eu\" dir=\"ltr\">orain dela <span class=\"u\">ordu</span> <span class=\"n\">1</span></time>"
+    );
+}
@@ -0,0 +1,92 @@
+//! Relative phrases combined with absolute timestamps, like "3 hours ago (Mar 5 at 14:05)".
+
+use super::{Formatter, Language};
+use chrono::{DateTime, Datelike, TimeZone, Timelike};
+
+/// Where the absolute timestamp goes in `Formatter::convert_hybrid`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum HybridPosition {
+    /// "Mar 5 at 14:05 (3 hours ago)"
+    Before,
+    /// "3 hours ago (Mar 5 at 14:05)"
+    After,
+}
+
+impl<L: Language> Formatter<L> {
+    /// Format the timespan like `convert_chrono` and add the absolute timestamp of `from`
+    /// in the time zone `tz` in parentheses.
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use timeago::HybridPosition;
+    /// let f = timeago::Formatter::new();
+    /// let now = Utc.with_ymd_and_hms(2024, 3, 5, 14, 0, 0).unwrap();
+    /// let from = Utc.with_ymd_and_hms(2024, 3, 5, 11, 5, 0).unwrap();
+    /// let tz = FixedOffset::east_opt(3 * 3600).unwrap();
+    /// assert_eq!(f.convert_hybrid(from, now, &tz, HybridPosition::After), "2 hours ago (Mar 5 at 14:05)");
+    /// assert_eq!(f.convert_hybrid(from, now, &Utc, HybridPosition::Before), "Mar 5 at 11:05 (2 hours ago)");
+    /// ```
+    pub fn convert_hybrid<Tz1, Tz2, Tz3>(
+        &self,
+        from: DateTime<Tz1>,
+        to: DateTime<Tz2>,
+        tz: &Tz3,
+        position: HybridPosition,
+    ) -> String
+    where
+        Tz1: TimeZone,
+        Tz2: TimeZone,
+        Tz3: TimeZone,
+    {
+        let (relative, absolute) = self.convert_hybrid_parts(from, to, tz);
+        match position {
+            HybridPosition::Before => format!("{absolute} ({relative})"),
+            HybridPosition::After => format!("{relative} ({absolute})"),
+        }
+    }
+
+    /// Like `convert_hybrid`, but return the relative and the absolute parts separately,
+    /// for example to show the latter as a tooltip.
+    /// The year is included in the absolute part only if it differs from the year of `to`.
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// # #[cfg(feature = "translations")] {
+    /// use chrono::{TimeZone, Utc};
+    /// let f = timeago::Formatter::with_language(timeago::languages::german::German);
+    /// let now = Utc.with_ymd_and_hms(2024, 3, 5, 14, 0, 0).unwrap();
+    /// let from = Utc.with_ymd_and_hms(2023, 12, 31, 9, 30, 0).unwrap();
+    /// let (relative, absolute) = f.convert_hybrid_parts(from, now, &Utc);
+    /// assert_eq!(relative, "vor 2 Monaten");
+    /// assert_eq!(absolute, "31. Dez. 2023 um 09:30");
+    /// # }
+    /// ```
+    pub fn convert_hybrid_parts<Tz1, Tz2, Tz3>(
+        &self,
+        from: DateTime<Tz1>,
+        to: DateTime<Tz2>,
+        tz: &Tz3,
+    ) -> (String, String)
+    where
+        Tz1: TimeZone,
+        Tz2: TimeZone,
+        Tz3: TimeZone,
+    {
+        let local = from.with_timezone(tz);
+        let current_year = to.with_timezone(tz).year();
+        let year = if local.year() == current_year {
+            None
+        } else {
+            Some(local.year())
+        };
+        let date = self.lang.absolute_date(local.day(), local.month(), year);
+        let time = format!("{:02}:{:02}", local.hour(), local.minute());
+        let absolute = self.lang.day_at_time(&date, &time);
+        (self.convert_chrono(from, to), absolute)
+    }
+}
@@ -0,0 +1,86 @@
+//! Stateful formatting which does not flap between units when durations jitter near a boundary.
+
+use super::{English, Formatter, Language, TimeUnit};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+
+/// Wrapper around `Formatter` remembering the unit last shown for each item, identified by a key.
+/// Switching to a bigger unit happens immediately, but switching back to a smaller one
+/// only when the duration drops below the bigger unit's threshold by more than the margin.
+/// ```
+/// use std::time::Duration;
+/// let mut h = timeago::Hysteresis::new(timeago::Formatter::new(), Duration::from_secs(30));
+/// assert_eq!(h.convert("a", Duration::from_secs(3599)), "59 minutes ago");
+/// assert_eq!(h.convert("a", Duration::from_secs(3600)), "1 hour ago");
+/// // jitter of the clock does not bring the minutes back
+/// assert_eq!(h.convert("a", Duration::from_secs(3590)), "1 hour ago");
+/// assert_eq!(h.convert("a", Duration::from_secs(3560)), "59 minutes ago");
+/// // other items are independent
+/// assert_eq!(h.convert("b", Duration::from_secs(3590)), "59 minutes ago");
+/// ```
+pub struct Hysteresis<K, L: Language = English> {
+    formatter: Formatter<L>,
+    margin: Duration,
+    last: HashMap<K, TimeUnit>,
+}
+
+impl<K: Eq + Hash, L: Language> Hysteresis<K, L> {
+    /// Constructor using the specified formatter and the margin for switching to smaller units
+    pub fn new(formatter: Formatter<L>, margin: Duration) -> Self {
+        Hysteresis {
+            formatter,
+            margin,
+            last: HashMap::new(),
+        }
+    }
+
+    /// Access the formatter to change its settings
+    pub fn formatter(&mut self) -> &mut Formatter<L> {
+        &mut self.formatter
+    }
+
+    /// Set the margin for switching to smaller units
+    pub fn margin(&mut self, x: Duration) -> &mut Self {
+        self.margin = x;
+        self
+    }
+
+    /// Format the duration of the item `key` like `Formatter::convert`,
+    /// but keep the unit shown last time for it if the duration is within the margin.
+    pub fn convert(&mut self, key: K, d: Duration) -> String {
+        let f = &self.formatter;
+        let unit = match f.chunk(d) {
+            Ok(Some((tu, _, _))) => Some(tu),
+            _ => None,
+        };
+        if let Some(&last) = self.last.get(&key) {
+            if unit.map_or(true, |tu| tu < last) {
+                let threshold = f.threshold_of(last);
+                if d.saturating_add(self.margin) >= threshold {
+                    // show it as if the bigger unit was just reached
+                    return f.convert(threshold);
+                }
+            }
+        }
+        match unit {
+            Some(tu) => {
+                self.last.insert(key, tu);
+            }
+            None => {
+                self.last.remove(&key);
+            }
+        }
+        f.convert(d)
+    }
+
+    /// Forget the unit remembered for the item
+    pub fn forget(&mut self, key: &K) {
+        self.last.remove(key);
+    }
+
+    /// Forget the units remembered for all items
+    pub fn clear(&mut self) {
+        self.last.clear();
+    }
+}
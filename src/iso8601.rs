@@ -0,0 +1,221 @@
+//! ISO 8601 durations like "PT1H2M3S", "P3W" or "P1Y2M".
+//!
+//! Years and months are converted using the same approximate lengths as the rest of the crate
+//! (about 30.4 days per month), weeks are 7 days.
+//!
+//! ```
+//! use std::time::Duration;
+//! use timeago::iso8601;
+//! assert_eq!(iso8601::format(Duration::from_secs(3723)), "PT1H2M3S");
+//! assert_eq!(iso8601::parse("PT1H2M3S"), Ok(Duration::from_secs(3723)));
+//! ```
+
+use super::parse::{nanos_to_duration, number, unexpected, ParseError, ParseErrorKind};
+use super::TimeUnit;
+use std::time::Duration;
+
+/// Designator letter and whether it belongs to the time part
+fn designator(tu: TimeUnit) -> Option<(char, bool)> {
+    use TimeUnit::*;
+    Some(match tu {
+        Years => ('Y', false),
+        Months => ('M', false),
+        Weeks => ('W', false),
+        Days => ('D', false),
+        Hours => ('H', true),
+        Minutes => ('M', true),
+        Seconds | Milliseconds | Microseconds | Nanoseconds => ('S', true),
+        Custom(_) => return None,
+    })
+}
+
+/// Format the duration using days, hours, minutes and seconds, like "P1DT2H3M4.5S".
+///
+/// Calendar units are not used, as their length is ambiguous for other systems.
+/// ```
+/// use std::time::Duration;
+/// use timeago::iso8601::format;
+/// assert_eq!(format(Duration::from_secs(0)), "PT0S");
+/// assert_eq!(format(Duration::from_millis(1500)), "PT1.5S");
+/// assert_eq!(format(Duration::from_secs(86400 + 3600)), "P1DT1H");
+/// ```
+pub fn format(d: Duration) -> String {
+    use TimeUnit::*;
+    format_units(d, &[Days, Hours, Minutes, Seconds])
+}
+
+/// Format the duration splitting it into the specified units, like "P3W" or "P1Y2M".
+///
+/// Units smaller than a second give fractional seconds, custom units are ignored.
+/// Remainder not representable by the smallest of the units is truncated.
+/// Without any units to use, the units of `format` are used.
+/// ```
+/// use std::time::Duration;
+/// use timeago::iso8601::format_units;
+/// use timeago::TimeUnit::*;
+/// assert_eq!(format_units(Duration::from_secs(21 * 86400), &[Weeks]), "P3W");
+/// assert_eq!(format_units(Duration::from_secs(22 * 86400), &[Weeks, Days]), "P3W1D");
+/// assert_eq!(format_units(Duration::from_secs(14 * 2_628_003), &[Years, Months]), "P1Y2M");
+/// assert_eq!(format_units(Duration::from_millis(90_250), &[Minutes, Milliseconds]), "PT1M30.25S");
+/// assert_eq!(format_units(Duration::from_secs(90), &[]), "PT1M30S");
+/// ```
+pub fn format_units(d: Duration, units: &[TimeUnit]) -> String {
+    let mut units: Vec<TimeUnit> = units
+        .iter()
+        .copied()
+        .filter(|x| designator(*x).is_some())
+        .map(|x| x.max(TimeUnit::Seconds).min(TimeUnit::Years))
+        .collect();
+    if units.is_empty() {
+        return format(d);
+    }
+    units.sort();
+    units.dedup();
+    units.reverse();
+    let fractional = units.last() == Some(&TimeUnit::Seconds);
+
+    let mut date = String::new();
+    let mut time = String::new();
+    let mut rem = d.as_nanos();
+    for tu in &units {
+        let unit = tu.min_duration().as_nanos();
+        let x = rem / unit;
+        rem %= unit;
+        let (letter, is_time) = designator(*tu).unwrap();
+        let target = if is_time { &mut time } else { &mut date };
+        if *tu == TimeUnit::Seconds && fractional && rem > 0 {
+            let frac = format!("{rem:09}");
+            target.push_str(&format!("{x}.{}{letter}", frac.trim_end_matches('0')));
+        } else if x > 0 {
+            target.push_str(&format!("{x}{letter}"));
+        }
+    }
+
+    if date.is_empty() && time.is_empty() {
+        return match units.last().and_then(|x| designator(*x)) {
+            Some((letter, false)) => format!("P0{letter}"),
+            _ => "PT0S".to_owned(),
+        };
+    }
+    if time.is_empty() {
+        format!("P{date}")
+    } else {
+        format!("P{date}T{time}")
+    }
+}
+
+/// Parse ISO 8601 duration like "PT1H2M3S", "P3W", "P1Y2M10DT2H30M" or "PT0.5S".
+///
+/// Comma is accepted as the decimal separator too. Only the last component may be fractional.
+/// Negative durations and the alternative format like "P0001-02-03T04:05:06" are not supported.
+/// ```
+/// use std::time::Duration;
+/// use timeago::iso8601::parse;
+/// use timeago::ParseErrorKind;
+/// assert_eq!(parse("P3W"), Ok(Duration::from_secs(21 * 86400)));
+/// assert_eq!(parse("PT0,5S"), Ok(Duration::from_millis(500)));
+/// assert_eq!(parse("P1M"), Ok(Duration::from_secs(2_628_003)));
+/// let e = parse("PT1S2M").unwrap_err();
+/// assert_eq!(e.position(), 5);
+/// assert_eq!(*e.kind(), ParseErrorKind::UnitOrder);
+/// ```
+pub fn parse(s: &str) -> Result<Duration, ParseError> {
+    use TimeUnit::*;
+    let b = s.as_bytes();
+    match b.first() {
+        Some(b'P') => (),
+        Some(b'-') => return Err(ParseError::new(0, ParseErrorKind::Negative)),
+        _ => return Err(unexpected(s, 0)),
+    }
+    let mut i = 1;
+    let mut in_time = false;
+    let mut previous: Option<TimeUnit> = None;
+    let mut fraction_seen = false;
+    let mut total: u128 = 0;
+    let mut ret = Duration::new(0, 0);
+    loop {
+        if i == b.len() {
+            if previous.is_none() || b[i - 1] == b'T' {
+                return Err(unexpected(s, i));
+            }
+            break;
+        }
+        if b[i] == b'T' && !in_time {
+            in_time = true;
+            i += 1;
+            continue;
+        }
+        let (n, j) = match number(s, i, b".,")? {
+            Some(x) => x,
+            None => return Err(unexpected(s, i)),
+        };
+        if fraction_seen {
+            return Err(ParseError::new(i, ParseErrorKind::MisplacedFraction));
+        }
+        let tu = match (b.get(j), in_time) {
+            (Some(b'Y'), false) => Years,
+            (Some(b'M'), false) => Months,
+            (Some(b'W'), false) => Weeks,
+            (Some(b'D'), false) => Days,
+            (Some(b'H'), true) => Hours,
+            (Some(b'M'), true) => Minutes,
+            (Some(b'S'), true) => Seconds,
+            (Some(_), _) => {
+                let u = s[j..].chars().next().unwrap().to_string();
+                return Err(ParseError::new(j, ParseErrorKind::UnknownUnit(u)));
+            }
+            (None, _) => return Err(unexpected(s, j)),
+        };
+        if previous.map_or(false, |p| p <= tu) {
+            return Err(ParseError::new(j, ParseErrorKind::UnitOrder));
+        }
+        previous = Some(tu);
+        fraction_seen = n.frac.is_some();
+        total = n
+            .nanos(tu.min_duration().as_nanos())
+            .and_then(|x| total.checked_add(x))
+            .ok_or_else(|| ParseError::new(i, ParseErrorKind::Overflow))?;
+        ret = nanos_to_duration(total, i)?;
+        i = j + 1;
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        use TimeUnit::*;
+        for secs in [0, 1, 59, 60, 3600, 86399, 86400, 1_000_000, 1_000_000_000] {
+            let d = Duration::new(secs, 250_000_000);
+            assert_eq!(parse(&format(d)), Ok(d));
+            let all = [Years, Months, Weeks, Days, Hours, Minutes, Seconds];
+            assert_eq!(parse(&format_units(d, &all)), Ok(d));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        use ParseErrorKind::*;
+        let e = |s: &str| {
+            let e = parse(s).unwrap_err();
+            (e.position(), e.kind().clone())
+        };
+        assert_eq!(e(""), (0, UnexpectedEnd));
+        assert_eq!(e("1H"), (0, UnexpectedChar('1')));
+        assert_eq!(e("-PT1S"), (0, Negative));
+        assert_eq!(e("P"), (1, UnexpectedEnd));
+        assert_eq!(e("PT"), (2, UnexpectedEnd));
+        assert_eq!(e("P1H"), (2, UnknownUnit("H".to_owned())));
+        assert_eq!(e("PT1D"), (3, UnknownUnit("D".to_owned())));
+        assert_eq!(e("P1D1D"), (4, UnitOrder));
+        assert_eq!(e("PT1.5M3S"), (6, MisplacedFraction));
+        assert_eq!(e("PT1."), (4, UnexpectedEnd));
+        assert_eq!(e("PT12"), (4, UnexpectedEnd));
+        assert_eq!(e("P99999999999999999999D"), (1, Overflow));
+        assert_eq!(e("P9999999999999999999Y"), (1, Overflow));
+        assert_eq!(e("PT1M18446744073709551615S"), (4, Overflow));
+    }
+}
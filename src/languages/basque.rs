@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Basque;
@@ -6,6 +6,9 @@ impl Language for Basque {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "eu"
+    }
     fn too_low(&self) -> &'static str {
         "oraintxe bertan"
     }
@@ -34,6 +37,122 @@ impl Language for Basque {
             Weeks => "aste",
             Months => "hile",
             Years => "urte",
+            Custom(c) => c.word(1),
+        }
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("atzo"),
+            (TimeUnit::Days, 2) => Some("herenegun"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "gaur"
+    }
+    fn yesterday(&self) -> &'static str {
+        "atzo"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "bihar"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day}, {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "datorren astelehenean",
+                "datorren asteartean",
+                "datorren asteazkenean",
+                "datorren ostegunean",
+                "datorren ostiralean",
+                "datorren larunbatean",
+                "datorren igandean",
+            ]
+        } else {
+            [
+                "joan den astelehenean",
+                "joan den asteartean",
+                "joan den asteazkenean",
+                "joan den ostegunean",
+                "joan den ostiralean",
+                "joan den larunbatean",
+                "joan den igandean",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "joan den astean",
+            (TimeUnit::Weeks, true) => "datorren astean",
+            (TimeUnit::Months, false) => "joan den hilean",
+            (TimeUnit::Months, true) => "datorren hilean",
+            (_, false) => "iaz",
+            (_, true) => "datorren urtean",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} e")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "urt.", "ots.", "mar.", "api.", "mai.", "eka.", "uzt.", "abu.", "ira.", "urr.", "aza.",
+            "abe.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{month} {day}"),
+            Some(year) => format!("{year}(e)ko {month} {day}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
+        match tu {
+            TimeUnit::Days => "lanegun",
+            _ => "lanordu",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Datozenak",
+            Today => "Gaur",
+            Yesterday => "Atzo",
+            ThisWeek => "Aste honetan",
+            LastWeek => "Joan den astean",
+            ThisMonth => "Hilabete honetan",
+            Older => "Lehenago",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "falta dira"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("{items} inguru"),
+            Qualifier::Over => format!("{items} baino gehiago"),
+            Qualifier::Almost => format!("ia {items}"),
+            Qualifier::LessThan => format!("{items} baino gutxiago"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "lehenago"),
+            Relation::After => ("", "geroago"),
+            Relation::Overdue => ("", "berandu"),
+            Relation::DueIn => ("", "barru"),
+            Relation::In => ("", "barru"),
+            _ => ("", ""),
+        }
+    }
+    fn place_reference_before(&self) -> bool {
+        true
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{ago} {from} eta {to} artean"),
+            None => format!("{from} eta {to} artean"),
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket, WordContext};
 
 #[derive(Default)]
 pub struct Belarusian;
@@ -16,6 +16,7 @@ impl Belarusian {
             Weeks => "тыдзень",
             Months => "месяц",
             Years => "год",
+            Custom(c) => c.word(1),
         }
     }
     fn genitive(&self, tu: TimeUnit) -> &'static str {
@@ -31,6 +32,7 @@ impl Belarusian {
             Weeks => "тыдні",
             Months => "месяца",
             Years => "гады",
+            Custom(c) => c.word(2),
         }
     }
     fn genitive_plural(&self, tu: TimeUnit) -> &'static str {
@@ -46,6 +48,39 @@ impl Belarusian {
             Weeks => "тыдняў",
             Months => "месяцаў",
             Years => "гадоў",
+            Custom(c) => c.word(2),
+        }
+    }
+    fn nominative(&self, tu: TimeUnit) -> &'static str {
+        use TimeUnit::*;
+        match tu {
+            Nanoseconds => "нанасэкунда",
+            Microseconds => "мікрасэкунда",
+            Milliseconds => "мілісэкунда",
+            Seconds => "сэкунда",
+            Minutes => "хвіліна",
+            Hours => "гадзіна",
+            Days => "дзень",
+            Weeks => "тыдзень",
+            Months => "месяц",
+            Years => "год",
+            Custom(c) => c.word(1),
+        }
+    }
+    fn genitive_singular(&self, tu: TimeUnit) -> &'static str {
+        use TimeUnit::*;
+        match tu {
+            Nanoseconds => "нанасэкунды",
+            Microseconds => "мікрасэкунды",
+            Milliseconds => "мілісэкунды",
+            Seconds => "сэкунды",
+            Minutes => "хвіліны",
+            Hours => "гадзіны",
+            Days => "дня",
+            Weeks => "тыдня",
+            Months => "месяца",
+            Years => "года",
+            Custom(c) => c.word(2),
         }
     }
 }
@@ -54,6 +89,9 @@ impl Language for Belarusian {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "be"
+    }
     fn too_low(&self) -> &'static str {
         "зараз"
     }
@@ -63,6 +101,9 @@ impl Language for Belarusian {
     fn ago(&self) -> &'static str {
         "таму"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str {
         let last_two = x % 100;
         let last = x % 10;
@@ -78,6 +119,152 @@ impl Language for Belarusian {
             }
         }
     }
+    fn get_word_in(&self, tu: TimeUnit, x: u64, context: WordContext) -> &'static str {
+        let one = x % 10 == 1 && x % 100 != 11;
+        match (context.qualifier, context.relation) {
+            // the qualifier takes the genitive
+            (Some(Qualifier::About | Qualifier::Between), _) if one => self.genitive_singular(tu),
+            (Some(Qualifier::About | Qualifier::Between), _) => self.genitive_plural(tu),
+            (None, Relation::Remaining) if one => self.nominative(tu),
+            _ => self.get_word(tu, x),
+        }
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        match tu {
+            TimeUnit::Custom(_) => None,
+            _ => Some(self.accusative(tu)),
+        }
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("учора"),
+            (TimeUnit::Days, 2) => Some("пазаўчора"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "сёння"
+    }
+    fn yesterday(&self) -> &'static str {
+        "учора"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "заўтра"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} у {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "у наступны панядзелак",
+                "у наступны аўторак",
+                "у наступную сераду",
+                "у наступны чацвер",
+                "у наступную пятніцу",
+                "у наступную суботу",
+                "у наступную нядзелю",
+            ]
+        } else {
+            [
+                "у мінулы панядзелак",
+                "у мінулы аўторак",
+                "у мінулую сераду",
+                "у мінулы чацвер",
+                "у мінулую пятніцу",
+                "у мінулую суботу",
+                "у мінулую нядзелю",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "на мінулым тыдні",
+            (TimeUnit::Weeks, true) => "на наступным тыдні",
+            (TimeUnit::Months, false) => "у мінулым месяцы",
+            (TimeUnit::Months, true) => "у наступным месяцы",
+            (_, false) => "у мінулым годзе",
+            (_, true) => "у наступным годзе",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} дз.")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "студ.",
+            "лют.",
+            "сак.",
+            "крас.",
+            "мая",
+            "чэрв.",
+            "ліп.",
+            "жн.",
+            "вер.",
+            "кастр.",
+            "ліст.",
+            "сняж.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year} г."),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        let (one, few, many) = match tu {
+            TimeUnit::Days => ("працоўны дзень", "працоўныя дні", "працоўных дзён"),
+            _ => ("працоўную гадзіну", "працоўныя гадзіны", "працоўных гадзін"),
+        };
+        let (last, last_two) = (x % 10, x % 100);
+        if last == 1 && last_two != 11 {
+            one
+        } else if (2..=4).contains(&last) && !(12..=14).contains(&last_two) {
+            few
+        } else {
+            many
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Будучыя",
+            Today => "Сёння",
+            Yesterday => "Учора",
+            ThisWeek => "На гэтым тыдні",
+            LastWeek => "На мінулым тыдні",
+            ThisMonth => "У гэтым месяцы",
+            Older => "Раней",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "засталося"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("каля {items}"),
+            Qualifier::Over => format!("больш за {items}"),
+            Qualifier::Almost => format!("амаль {items}"),
+            Qualifier::LessThan => format!("менш за {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("за", "да"),
+            Relation::After => ("праз", "пасля"),
+            Relation::Overdue => ("пратэрмінавана на", ""),
+            Relation::DueIn => ("праз", ""),
+            Relation::In => ("праз", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("ад {from} да {to} {ago}"),
+            None => format!("ад {from} да {to}"),
+        }
+    }
 }
 
 #[test]
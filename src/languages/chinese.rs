@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Fraction, Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Chinese;
@@ -6,6 +6,9 @@ impl Language for Chinese {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "zh"
+    }
     fn too_low(&self) -> &'static str {
         "刚刚"
     }
@@ -15,6 +18,9 @@ impl Language for Chinese {
     fn ago(&self) -> &'static str {
         "之前"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
         use TimeUnit::*;
         match tu {
@@ -28,6 +34,113 @@ impl Language for Chinese {
             Weeks => "周",
             Months => "月",
             Years => "年",
+            Custom(c) => c.word(1),
+        }
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("昨天"),
+            (TimeUnit::Days, 2) => Some("前天"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "今天"
+    }
+    fn yesterday(&self) -> &'static str {
+        "昨天"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "明天"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, _next: bool) -> &'static str {
+        ["周一", "周二", "周三", "周四", "周五", "周六", "周日"][weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "上周",
+            (TimeUnit::Weeks, true) => "下周",
+            (TimeUnit::Months, false) => "上个月",
+            (TimeUnit::Months, true) => "下个月",
+            (_, false) => "去年",
+            (_, true) => "明年",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x}天")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        match year {
+            None => format!("{month}月{day}日"),
+            Some(year) => format!("{year}年{month}月{day}日"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
+        match tu {
+            TimeUnit::Days => "工作日",
+            _ => "工作小时",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "即将",
+            Today => "今天",
+            Yesterday => "昨天",
+            ThisWeek => "本周",
+            LastWeek => "上周",
+            ThisMonth => "本月",
+            Older => "更早",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "还剩"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("大约 {items}"),
+            Qualifier::Over => format!("超过 {items}"),
+            Qualifier::Almost => format!("将近 {items}"),
+            Qualifier::LessThan => format!("不到 {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("前", ""),
+            Relation::After => ("后", ""),
+            Relation::Overdue => ("逾期", ""),
+            Relation::DueIn => ("", "后到期"),
+            Relation::In => ("", "之后"),
+            _ => ("", ""),
+        }
+    }
+    fn place_reference_before(&self) -> bool {
+        true
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{from} 到 {to} {ago}"),
+            None => format!("{from} 到 {to} 之间"),
+        }
+    }
+    fn fraction(&self, tu: TimeUnit, x: u64, fraction: Fraction) -> Option<String> {
+        use TimeUnit::*;
+        match (fraction, x, tu) {
+            (Fraction::Quarter, 0, Hours) => Some("一刻钟".to_owned()),
+            (Fraction::Half, 0, Minutes) => Some("半分钟".to_owned()),
+            (Fraction::Half, 0, Hours) => Some("半小时".to_owned()),
+            (Fraction::Half, 0, Days) => Some("半天".to_owned()),
+            (Fraction::Half, 0, Months) => Some("半个月".to_owned()),
+            (Fraction::Half, 0, Years) => Some("半年".to_owned()),
+            (Fraction::Half, x, Hours) if x > 0 => Some(format!("{x}个半小时")),
+            (Fraction::Half, x, Days) if x > 0 => Some(format!("{x}天半")),
+            (Fraction::Half, x, Months) if x > 0 => Some(format!("{x}个半月")),
+            (Fraction::Half, x, Years) if x > 0 => Some(format!("{x}年半")),
+            _ => None,
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Danish;
@@ -6,6 +6,9 @@ impl Language for Danish {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "da"
+    }
     fn too_low(&self) -> &'static str {
         "nu"
     }
@@ -29,6 +32,7 @@ impl Language for Danish {
                 Weeks => "uge",
                 Months => "måned",
                 Years => "år",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,7 +46,138 @@ impl Language for Danish {
                 Weeks => "uger",
                 Months => "måneder",
                 Years => "år",
+                Custom(c) => c.word(x),
             }
         }
     }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "et nanosekund",
+            Microseconds => "et mikrosekund",
+            Milliseconds => "et millisekund",
+            Seconds => "et sekund",
+            Minutes => "et minut",
+            Hours => "en time",
+            Days => "en dag",
+            Weeks => "en uge",
+            Months => "en måned",
+            Years => "et år",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("i går"),
+            (TimeUnit::Days, 2) => Some("i forgårs"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "i dag"
+    }
+    fn yesterday(&self) -> &'static str {
+        "i går"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "i morgen"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} kl. {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "næste mandag",
+                "næste tirsdag",
+                "næste onsdag",
+                "næste torsdag",
+                "næste fredag",
+                "næste lørdag",
+                "næste søndag",
+            ]
+        } else {
+            [
+                "i mandags",
+                "i tirsdags",
+                "i onsdags",
+                "i torsdags",
+                "i fredags",
+                "i lørdags",
+                "i søndags",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "i sidste uge",
+            (TimeUnit::Weeks, true) => "i næste uge",
+            (TimeUnit::Months, false) => "i sidste måned",
+            (TimeUnit::Months, true) => "i næste måned",
+            (_, false) => "sidste år",
+            (_, true) => "næste år",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} d")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day}. {month}"),
+            Some(year) => format!("{day}. {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "arbejdsdag",
+            (TimeUnit::Days, _) => "arbejdsdage",
+            (_, 1) => "arbejdstime",
+            _ => "arbejdstimer",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Kommende",
+            Today => "I dag",
+            Yesterday => "I går",
+            ThisWeek => "Denne uge",
+            LastWeek => "Sidste uge",
+            ThisMonth => "Denne måned",
+            Older => "Tidligere",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "tilbage"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("omkring {items}"),
+            Qualifier::Over => format!("over {items}"),
+            Qualifier::Almost => format!("næsten {items}"),
+            Qualifier::LessThan => format!("mindre end {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "før"),
+            Relation::After => ("", "efter"),
+            Relation::Overdue => ("", "forsinket"),
+            Relation::DueIn => ("forfalder om", ""),
+            Relation::In => ("om", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("mellem {from} og {to} {ago}"),
+            None => format!("mellem {from} og {to}"),
+        }
+    }
 }
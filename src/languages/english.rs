@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Fraction, Language, TimeUnit};
 
 /// Default language for timeago
 #[derive(Default)]
@@ -30,6 +30,7 @@ impl Language for English {
                 Weeks => "week",
                 Months => "month",
                 Years => "year",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -43,7 +44,52 @@ impl Language for English {
                 Weeks => "weeks",
                 Months => "months",
                 Years => "years",
+                Custom(c) => c.word(x),
             }
         }
     }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "a nanosecond",
+            Microseconds => "a microsecond",
+            Milliseconds => "a millisecond",
+            Seconds => "a second",
+            Minutes => "a minute",
+            Hours => "an hour",
+            Days => "a day",
+            Weeks => "a week",
+            Months => "a month",
+            Years => "a year",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("yesterday"),
+            _ => None,
+        }
+    }
+    fn fraction(&self, tu: TimeUnit, x: u64, fraction: Fraction) -> Option<String> {
+        use TimeUnit::*;
+        match (fraction, x) {
+            (Fraction::Quarter, 0) if tu == Hours => Some("a quarter of an hour".to_owned()),
+            (Fraction::Half, 0) => match tu {
+                Minutes => Some("half a minute".to_owned()),
+                Hours => Some("half an hour".to_owned()),
+                Days => Some("half a day".to_owned()),
+                Years => Some("half a year".to_owned()),
+                _ => None,
+            },
+            (Fraction::Half, 1) => {
+                let one = self.one_unit(tu)?;
+                Some(format!("{one} and a half"))
+            }
+            (Fraction::Half, x) => match tu {
+                Custom(_) => None,
+                _ => Some(format!("{x} and a half {}", self.get_word(tu, x))),
+            },
+            _ => None,
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct French;
@@ -6,6 +6,9 @@ impl Language for French {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "fr"
+    }
     fn too_low(&self) -> &'static str {
         "maintenant"
     }
@@ -29,6 +32,7 @@ impl Language for French {
                 Weeks => "semaine",
                 Months => "mois",
                 Years => "année",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,18 +46,169 @@ impl Language for French {
                 Weeks => "semaines",
                 Months => "mois",
                 Years => "ans",
+                Custom(c) => c.word(x),
             }
         }
     }
     fn place_ago_before(&self) -> bool {
         true
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "une nanoseconde",
+            Microseconds => "une microseconde",
+            Milliseconds => "une milliseconde",
+            Seconds => "une seconde",
+            Minutes => "une minute",
+            Hours => "une heure",
+            Days => "un jour",
+            Weeks => "une semaine",
+            Months => "un mois",
+            Years => "un an",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("hier"),
+            (TimeUnit::Days, 2) => Some("avant-hier"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "aujourd'hui"
+    }
+    fn yesterday(&self) -> &'static str {
+        "hier"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "demain"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} à {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "lundi prochain",
+                "mardi prochain",
+                "mercredi prochain",
+                "jeudi prochain",
+                "vendredi prochain",
+                "samedi prochain",
+                "dimanche prochain",
+            ]
+        } else {
+            [
+                "lundi dernier",
+                "mardi dernier",
+                "mercredi dernier",
+                "jeudi dernier",
+                "vendredi dernier",
+                "samedi dernier",
+                "dimanche dernier",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "la semaine dernière",
+            (TimeUnit::Weeks, true) => "la semaine prochaine",
+            (TimeUnit::Months, false) => "le mois dernier",
+            (TimeUnit::Months, true) => "le mois prochain",
+            (_, false) => "l'année dernière",
+            (_, true) => "l'année prochaine",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} j")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "jour ouvré",
+            (TimeUnit::Days, _) => "jours ouvrés",
+            (_, 1) => "heure ouvrée",
+            _ => "heures ouvrées",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "À venir",
+            Today => "Aujourd'hui",
+            Yesterday => "Hier",
+            ThisWeek => "Cette semaine",
+            LastWeek => "La semaine dernière",
+            ThisMonth => "Ce mois-ci",
+            Older => "Plus ancien",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "il reste"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("environ {items}"),
+            Qualifier::Over => format!("plus {}", de(items)),
+            Qualifier::Almost => format!("presque {items}"),
+            Qualifier::LessThan => format!("moins {}", de(items)),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "avant"),
+            Relation::After => ("", "après"),
+            Relation::Overdue => ("en retard de", ""),
+            Relation::DueIn => ("échéance dans", ""),
+            Relation::In => ("dans", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{ago} entre {from} et {to}"),
+            None => format!("entre {from} et {to}"),
+        }
+    }
+}
+
+/// "de" before the items, elided before a vowel like in "moins d'une minute"
+fn de(items: &str) -> String {
+    match items.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'é' | 'h') => format!("d'{items}"),
+        _ => format!("de {items}"),
+    }
 }
 
 #[test]
 fn test() {
-    use super::super::Formatter;
+    use super::super::{Formatter, TimeUnit};
     use std::time::Duration;
-    let f = Formatter::with_language(French);
+    let mut f = Formatter::with_language(French);
     assert_eq!(f.convert(Duration::from_secs(60)), "il y a 1 minute");
+    f.qualifiers(true).min_unit(TimeUnit::Minutes);
+    assert_eq!(
+        f.convert(Duration::from_secs(30)),
+        "il y a moins d'une minute"
+    );
+    assert_eq!(
+        f.convert(Duration::from_secs(3600 + 1800)),
+        "il y a plus de 1 heure"
+    );
 }
@@ -1,4 +1,6 @@
-use super::super::{Language, TimeUnit};
+use super::super::{
+    Fraction, Language, Qualifier, Relation, TimeUnit, TimelineBucket, WordContext,
+};
 
 #[derive(Default)]
 pub struct German;
@@ -6,6 +8,9 @@ impl Language for German {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "de"
+    }
     fn too_low(&self) -> &'static str {
         "jetzt"
     }
@@ -29,6 +34,7 @@ impl Language for German {
                 Weeks => "Woche",
                 Months => "Monat",
                 Years => "Jahr",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,18 +48,223 @@ impl Language for German {
                 Weeks => "Wochen",
                 Months => "Monaten",
                 Years => "Jahren",
+                Custom(c) => c.word(x),
             }
         }
     }
+    fn get_word_in(&self, tu: TimeUnit, x: u64, context: WordContext) -> &'static str {
+        // nominative plural unless after "vor", "in" or "zwischen"
+        match (context.relation, tu) {
+            (Relation::Ago | Relation::DueIn | Relation::In, _) => self.get_word(tu, x),
+            // dative after "zwischen"
+            _ if context.qualifier == Some(Qualifier::Between) => self.get_word(tu, x),
+            (_, TimeUnit::Days) if x != 1 => "Tage",
+            (_, TimeUnit::Months) if x != 1 => "Monate",
+            (_, TimeUnit::Years) if x != 1 => "Jahre",
+            _ => self.get_word(tu, x),
+        }
+    }
     fn place_ago_before(&self) -> bool {
         true
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "einer Nanosekunde",
+            Microseconds => "einer Mikrosekunde",
+            Milliseconds => "einer Millisekunde",
+            Seconds => "einer Sekunde",
+            Minutes => "einer Minute",
+            Hours => "einer Stunde",
+            Days => "einem Tag",
+            Weeks => "einer Woche",
+            Months => "einem Monat",
+            Years => "einem Jahr",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("gestern"),
+            (TimeUnit::Days, 2) => Some("vorgestern"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "heute"
+    }
+    fn yesterday(&self) -> &'static str {
+        "gestern"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "morgen"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} um {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "nächsten Montag",
+                "nächsten Dienstag",
+                "nächsten Mittwoch",
+                "nächsten Donnerstag",
+                "nächsten Freitag",
+                "nächsten Samstag",
+                "nächsten Sonntag",
+            ]
+        } else {
+            [
+                "letzten Montag",
+                "letzten Dienstag",
+                "letzten Mittwoch",
+                "letzten Donnerstag",
+                "letzten Freitag",
+                "letzten Samstag",
+                "letzten Sonntag",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "letzte Woche",
+            (TimeUnit::Weeks, true) => "nächste Woche",
+            (TimeUnit::Months, false) => "letzten Monat",
+            (TimeUnit::Months, true) => "nächsten Monat",
+            (_, false) => "letztes Jahr",
+            (_, true) => "nächstes Jahr",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} Tg.")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day}. {month}"),
+            Some(year) => format!("{day}. {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "Werktag",
+            (TimeUnit::Days, _) => "Werktagen",
+            (_, 1) => "Arbeitsstunde",
+            _ => "Arbeitsstunden",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Demnächst",
+            Today => "Heute",
+            Yesterday => "Gestern",
+            ThisWeek => "Diese Woche",
+            LastWeek => "Letzte Woche",
+            ThisMonth => "Diesen Monat",
+            Older => "Früher",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "noch"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("etwa {items}"),
+            Qualifier::Over => format!("über {items}"),
+            Qualifier::Almost => format!("fast {items}"),
+            Qualifier::LessThan => format!("weniger als {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "vor"),
+            Relation::After => ("", "nach"),
+            Relation::Overdue => ("", "überfällig"),
+            Relation::DueIn => ("fällig in", ""),
+            Relation::In => ("in", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{ago} {from} bis {to}"),
+            None => format!("zwischen {from} und {to}"),
+        }
+    }
+    fn fraction(&self, tu: TimeUnit, x: u64, fraction: Fraction) -> Option<String> {
+        use TimeUnit::*;
+        let phrase = match (fraction, x, tu) {
+            (Fraction::Quarter, 0, Hours) => "einer Viertelstunde",
+            (Fraction::Half, 0, Minutes) => "einer halben Minute",
+            (Fraction::Half, 0, Hours) => "einer halben Stunde",
+            (Fraction::Half, 0, Days) => "einem halben Tag",
+            (Fraction::Half, 0, Years) => "einem halben Jahr",
+            (Fraction::Half, 1, Minutes) => "anderthalb Minuten",
+            (Fraction::Half, 1, Hours) => "anderthalb Stunden",
+            (Fraction::Half, 1, Days) => "anderthalb Tagen",
+            (Fraction::Half, 1, Weeks) => "anderthalb Wochen",
+            (Fraction::Half, 1, Months) => "anderthalb Monaten",
+            (Fraction::Half, 1, Years) => "anderthalb Jahren",
+            _ => return None,
+        };
+        Some(phrase.to_owned())
+    }
 }
 
 #[test]
 fn test() {
     use super::super::Formatter;
     use std::time::Duration;
-    let f = Formatter::with_language(German);
+    let mut f = Formatter::with_language(German);
     assert_eq!(f.convert(Duration::from_secs(60)), "vor 1 Minute");
+    f.idioms(true);
+    assert_eq!(f.convert(Duration::from_secs(60)), "vor einer Minute");
+    assert_eq!(f.convert(Duration::from_secs(24 * 3600)), "gestern");
+    assert_eq!(f.convert(Duration::from_secs(2 * 24 * 3600)), "vorgestern");
+    assert_eq!(f.convert(Duration::from_secs(3 * 24 * 3600)), "vor 3 Tagen");
+    assert_eq!(
+        f.convert(Duration::from_secs(10 * 24 * 3600)),
+        "vor einer Woche"
+    );
+    assert_eq!(
+        f.convert(Duration::from_secs(366 * 24 * 3600)),
+        "vor einem Jahr"
+    );
+
+    f.absolute_after(Duration::from_secs(7 * 24 * 3600));
+    let now = std::time::UNIX_EPOCH + Duration::from_secs(1_710_410_400);
+    let day = Duration::from_secs(24 * 3600);
+    assert_eq!(f.convert_system_time(now - 9 * day, now), "5. März");
+    assert_eq!(f.convert_system_time(now - 375 * day, now), "5. März 2023");
+
+    let start = std::time::Instant::now();
+    let mut eta = super::super::Eta::with_formatter(Formatter::with_language(German), start);
+    eta.update(0.25, start + day);
+    assert_eq!(eta.convert().unwrap(), "noch etwa 3 Tage");
+    eta.approximate(false);
+    assert_eq!(eta.convert().unwrap(), "noch 3 Tage");
+
+    let mut f = Formatter::with_language(German);
+    f.relation(super::super::Relation::Before)
+        .reference("dem Release");
+    assert_eq!(f.convert(3 * day), "3 Tage vor dem Release");
+    f.relation(super::super::Relation::DueIn);
+    assert_eq!(f.convert(3 * day), "fällig in 3 Tagen");
+
+    let mut f = Formatter::with_language(German);
+    f.qualifiers(true).min_unit(super::super::TimeUnit::Minutes);
+    assert_eq!(f.convert(3 * day + day / 2), "vor über 3 Tagen");
+    assert_eq!(
+        f.convert(Duration::from_secs(30)),
+        "vor weniger als einer Minute"
+    );
 }
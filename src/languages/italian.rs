@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Italian;
@@ -6,6 +6,9 @@ impl Language for Italian {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "it"
+    }
     fn too_low(&self) -> &'static str {
         "adesso"
     }
@@ -15,6 +18,9 @@ impl Language for Italian {
     fn ago(&self) -> &'static str {
         "fa"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str {
         use TimeUnit::*;
         if x == 1 {
@@ -29,6 +35,7 @@ impl Language for Italian {
                 Weeks => "settimana",
                 Months => "mese",
                 Years => "anno",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,9 +49,139 @@ impl Language for Italian {
                 Weeks => "settimane",
                 Months => "mesi",
                 Years => "anni",
+                Custom(c) => c.word(x),
             }
         }
     }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "un nanosecondo",
+            Microseconds => "un microsecondo",
+            Milliseconds => "un millisecondo",
+            Seconds => "un secondo",
+            Minutes => "un minuto",
+            Hours => "un'ora",
+            Days => "un giorno",
+            Weeks => "una settimana",
+            Months => "un mese",
+            Years => "un anno",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("ieri"),
+            (TimeUnit::Days, 2) => Some("l'altro ieri"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "oggi"
+    }
+    fn yesterday(&self) -> &'static str {
+        "ieri"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "domani"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} alle {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "lunedì prossimo",
+                "martedì prossimo",
+                "mercoledì prossimo",
+                "giovedì prossimo",
+                "venerdì prossimo",
+                "sabato prossimo",
+                "domenica prossima",
+            ]
+        } else {
+            [
+                "lunedì scorso",
+                "martedì scorso",
+                "mercoledì scorso",
+                "giovedì scorso",
+                "venerdì scorso",
+                "sabato scorso",
+                "domenica scorsa",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "la settimana scorsa",
+            (TimeUnit::Weeks, true) => "la settimana prossima",
+            (TimeUnit::Months, false) => "il mese scorso",
+            (TimeUnit::Months, true) => "il mese prossimo",
+            (_, false) => "l'anno scorso",
+            (_, true) => "l'anno prossimo",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} g")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "giorno lavorativo",
+            (TimeUnit::Days, _) => "giorni lavorativi",
+            (_, 1) => "ora lavorativa",
+            _ => "ore lavorative",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "In arrivo",
+            Today => "Oggi",
+            Yesterday => "Ieri",
+            ThisWeek => "Questa settimana",
+            LastWeek => "La settimana scorsa",
+            ThisMonth => "Questo mese",
+            Older => "Meno recenti",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "ancora"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("circa {items}"),
+            Qualifier::Over => format!("più di {items}"),
+            Qualifier::Almost => format!("quasi {items}"),
+            Qualifier::LessThan => format!("meno di {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "prima"),
+            Relation::After => ("", "dopo"),
+            Relation::Overdue => ("in ritardo di", ""),
+            Relation::DueIn => ("scade tra", ""),
+            Relation::In => ("tra", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("tra {from} e {to} {ago}"),
+            None => format!("tra {from} e {to}"),
+        }
+    }
 }
 
 #[test]
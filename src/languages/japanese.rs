@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Fraction, Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Japanese;
@@ -6,6 +6,9 @@ impl Language for Japanese {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "ja"
+    }
     fn too_low(&self) -> &'static str {
         "今"
     }
@@ -15,6 +18,9 @@ impl Language for Japanese {
     fn ago(&self) -> &'static str {
         "前"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
         use TimeUnit::*;
         match tu {
@@ -28,6 +34,7 @@ impl Language for Japanese {
             Weeks => "週間",
             Months => "月",
             Years => "年",
+            Custom(c) => c.word(1),
         }
     }
     // Japanese doesn't separate words with spaces, so drop the default " "
@@ -41,6 +48,131 @@ impl Language for Japanese {
     fn override_space_near_ago(&self) -> &str {
         ""
     }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("昨日"),
+            (TimeUnit::Days, 2) => Some("一昨日"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "今日"
+    }
+    fn yesterday(&self) -> &'static str {
+        "昨日"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "明日"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "今度の月曜日",
+                "今度の火曜日",
+                "今度の水曜日",
+                "今度の木曜日",
+                "今度の金曜日",
+                "今度の土曜日",
+                "今度の日曜日",
+            ]
+        } else {
+            [
+                "この前の月曜日",
+                "この前の火曜日",
+                "この前の水曜日",
+                "この前の木曜日",
+                "この前の金曜日",
+                "この前の土曜日",
+                "この前の日曜日",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "先週",
+            (TimeUnit::Weeks, true) => "来週",
+            (TimeUnit::Months, false) => "先月",
+            (TimeUnit::Months, true) => "来月",
+            (_, false) => "去年",
+            (_, true) => "来年",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x}日")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        match year {
+            None => format!("{month}月{day}日"),
+            Some(year) => format!("{year}年{month}月{day}日"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
+        match tu {
+            TimeUnit::Days => "営業日",
+            _ => "営業時間",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "今後",
+            Today => "今日",
+            Yesterday => "昨日",
+            ThisWeek => "今週",
+            LastWeek => "先週",
+            ThisMonth => "今月",
+            Older => "それ以前",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "残り"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("約{items}"),
+            Qualifier::Over => format!("{items}以上"),
+            Qualifier::Almost => format!("ほぼ{items}"),
+            Qualifier::LessThan => format!("{items}未満"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "前"),
+            Relation::After => ("", "後"),
+            Relation::Overdue => ("", "超過"),
+            Relation::DueIn => ("", "後に期限"),
+            Relation::In => ("", "後"),
+            _ => ("", ""),
+        }
+    }
+    fn place_reference_before(&self) -> bool {
+        true
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{from}から{to}{ago}"),
+            None => format!("{from}から{to}の間"),
+        }
+    }
+    fn range_separator(&self, _shared_unit: bool) -> &'static str {
+        "〜"
+    }
+    fn fraction(&self, tu: TimeUnit, x: u64, fraction: Fraction) -> Option<String> {
+        use TimeUnit::*;
+        match (fraction, x, tu) {
+            (Fraction::Half, 0, Days) => Some("半日".to_owned()),
+            (Fraction::Half, 0, Years) => Some("半年".to_owned()),
+            (Fraction::Half, x, Hours) if x > 0 => Some(format!("{x}時間半")),
+            (Fraction::Half, x, Days) if x > 0 => Some(format!("{x}日半")),
+            (Fraction::Half, x, Years) if x > 0 => Some(format!("{x}年半")),
+            _ => None,
+        }
+    }
 }
 
 #[test]
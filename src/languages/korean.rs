@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Korean;
@@ -6,6 +6,9 @@ impl Language for Korean {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "ko"
+    }
     fn too_low(&self) -> &'static str {
         "방금"
     }
@@ -29,19 +32,134 @@ impl Language for Korean {
             Weeks => "주",
             Months => "개월",
             Years => "년",
+            Custom(c) => c.word(1),
         }
     }
     fn between_value_and_word(&self) -> &str {
         // https://www.korean.go.kr/kornorms/regltn/regltnView.do?regltn_code=0001&regltn_no=263
         ""
     }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("어제"),
+            (TimeUnit::Days, 2) => Some("그저께"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "오늘"
+    }
+    fn yesterday(&self) -> &'static str {
+        "어제"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "내일"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "다음 월요일",
+                "다음 화요일",
+                "다음 수요일",
+                "다음 목요일",
+                "다음 금요일",
+                "다음 토요일",
+                "다음 일요일",
+            ]
+        } else {
+            [
+                "지난 월요일",
+                "지난 화요일",
+                "지난 수요일",
+                "지난 목요일",
+                "지난 금요일",
+                "지난 토요일",
+                "지난 일요일",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "지난주",
+            (TimeUnit::Weeks, true) => "다음 주",
+            (TimeUnit::Months, false) => "지난달",
+            (TimeUnit::Months, true) => "다음 달",
+            (_, false) => "작년",
+            (_, true) => "내년",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x}일")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        match year {
+            None => format!("{month}월 {day}일"),
+            Some(year) => format!("{year}년 {month}월 {day}일"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
+        match tu {
+            TimeUnit::Days => "영업일",
+            _ => "업무 시간",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "예정",
+            Today => "오늘",
+            Yesterday => "어제",
+            ThisWeek => "이번 주",
+            LastWeek => "지난주",
+            ThisMonth => "이번 달",
+            Older => "이전",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "남음"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("약 {items}"),
+            Qualifier::Over => format!("{items} 이상"),
+            Qualifier::Almost => format!("거의 {items}"),
+            Qualifier::LessThan => format!("{items} 미만"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "전"),
+            Relation::After => ("", "후"),
+            Relation::Overdue => ("", "연체"),
+            Relation::DueIn => ("", "후 마감"),
+            Relation::In => ("", "후"),
+            _ => ("", ""),
+        }
+    }
+    fn place_reference_before(&self) -> bool {
+        true
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{from}에서 {to} {ago}"),
+            None => format!("{from}에서 {to} 사이"),
+        }
+    }
+    fn range_separator(&self, _shared_unit: bool) -> &'static str {
+        "~"
+    }
 }
 
 #[test]
 fn test() {
     use super::super::Formatter;
     use std::time::Duration;
-    
+
     fn test_with_formatter<L: Language>(mut f: Formatter<L>) {
         f.min_unit(TimeUnit::Seconds);
         assert_eq!(f.convert(Duration::from_secs(0)), "방금");
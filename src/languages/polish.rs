@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket, WordContext};
 
 #[derive(Default)]
 pub struct Polish;
@@ -16,6 +16,7 @@ impl Polish {
             Weeks => "tydzień",
             Months => "miesiąc",
             Years => "lat",
+            Custom(c) => c.word(1),
         }
     }
     fn genitive(&self, tu: TimeUnit) -> &'static str {
@@ -31,6 +32,7 @@ impl Polish {
             Weeks => "tygodnie",
             Months => "miesiące",
             Years => "lata",
+            Custom(c) => c.word(2),
         }
     }
     fn genitive_plural(&self, tu: TimeUnit) -> &'static str {
@@ -46,6 +48,39 @@ impl Polish {
             Weeks => "tygodni",
             Months => "miesięcy",
             Years => "lat",
+            Custom(c) => c.word(2),
+        }
+    }
+    fn nominative(&self, tu: TimeUnit) -> &'static str {
+        use TimeUnit::*;
+        match tu {
+            Nanoseconds => "nanosekunda",
+            Microseconds => "mikrosekunda",
+            Milliseconds => "milisekunda",
+            Seconds => "sekunda",
+            Minutes => "minuta",
+            Hours => "godzina",
+            Days => "dzień",
+            Weeks => "tydzień",
+            Months => "miesiąc",
+            Years => "rok",
+            Custom(c) => c.word(1),
+        }
+    }
+    fn genitive_singular(&self, tu: TimeUnit) -> &'static str {
+        use TimeUnit::*;
+        match tu {
+            Nanoseconds => "nanosekundy",
+            Microseconds => "mikrosekundy",
+            Milliseconds => "milisekundy",
+            Seconds => "sekundy",
+            Minutes => "minuty",
+            Hours => "godziny",
+            Days => "dnia",
+            Weeks => "tygodnia",
+            Months => "miesiąca",
+            Years => "roku",
+            Custom(c) => c.word(2),
         }
     }
 }
@@ -53,6 +88,9 @@ impl Language for Polish {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "pl"
+    }
     fn too_low(&self) -> &'static str {
         "teraz"
     }
@@ -62,6 +100,9 @@ impl Language for Polish {
     fn ago(&self) -> &'static str {
         "temu"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str {
         if tu == TimeUnit::Years && x == 1 {
             return "rok";
@@ -81,6 +122,142 @@ impl Language for Polish {
             }
         }
     }
+    fn get_word_in(&self, tu: TimeUnit, x: u64, context: WordContext) -> &'static str {
+        let one = x == 1;
+        match (context.qualifier, context.relation) {
+            // the qualifier takes the genitive
+            (Some(Qualifier::About | Qualifier::Between), _) if one => self.genitive_singular(tu),
+            (Some(Qualifier::About | Qualifier::Between), _) => self.genitive_plural(tu),
+            (None, Relation::Remaining) if one => self.nominative(tu),
+            _ => self.get_word(tu, x),
+        }
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        match tu {
+            TimeUnit::Custom(_) => None,
+            TimeUnit::Years => Some("rok"),
+            _ => Some(self.accusative(tu)),
+        }
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("wczoraj"),
+            (TimeUnit::Days, 2) => Some("przedwczoraj"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "dzisiaj"
+    }
+    fn yesterday(&self) -> &'static str {
+        "wczoraj"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "jutro"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} o {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "w przyszły poniedziałek",
+                "w przyszły wtorek",
+                "w przyszłą środę",
+                "w przyszły czwartek",
+                "w przyszły piątek",
+                "w przyszłą sobotę",
+                "w przyszłą niedzielę",
+            ]
+        } else {
+            [
+                "w zeszły poniedziałek",
+                "w zeszły wtorek",
+                "w zeszłą środę",
+                "w zeszły czwartek",
+                "w zeszły piątek",
+                "w zeszłą sobotę",
+                "w zeszłą niedzielę",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "w zeszłym tygodniu",
+            (TimeUnit::Weeks, true) => "w przyszłym tygodniu",
+            (TimeUnit::Months, false) => "w zeszłym miesiącu",
+            (TimeUnit::Months, true) => "w przyszłym miesiącu",
+            (_, false) => "w zeszłym roku",
+            (_, true) => "w przyszłym roku",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} d.")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        let (one, few, many) = match tu {
+            TimeUnit::Days => ("dzień roboczy", "dni robocze", "dni roboczych"),
+            _ => ("godzinę roboczą", "godziny robocze", "godzin roboczych"),
+        };
+        let (last, last_two) = (x % 10, x % 100);
+        if x == 1 {
+            one
+        } else if (2..=4).contains(&last) && !(12..=14).contains(&last_two) {
+            few
+        } else {
+            many
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Nadchodzące",
+            Today => "Dzisiaj",
+            Yesterday => "Wczoraj",
+            ThisWeek => "W tym tygodniu",
+            LastWeek => "W zeszłym tygodniu",
+            ThisMonth => "W tym miesiącu",
+            Older => "Wcześniej",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "pozostało"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("około {items}"),
+            Qualifier::Over => format!("ponad {items}"),
+            Qualifier::Almost => format!("prawie {items}"),
+            Qualifier::LessThan => format!("mniej niż {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "przed"),
+            Relation::After => ("", "po"),
+            Relation::Overdue => ("spóźnione o", ""),
+            Relation::DueIn => ("za", ""),
+            Relation::In => ("za", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("od {from} do {to} {ago}"),
+            None => format!("od {from} do {to}"),
+        }
+    }
 }
 
 #[test]
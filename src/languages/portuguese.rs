@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Portuguese;
@@ -6,6 +6,9 @@ impl Language for Portuguese {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "pt"
+    }
     fn too_low(&self) -> &'static str {
         "agora"
     }
@@ -15,6 +18,9 @@ impl Language for Portuguese {
     fn ago(&self) -> &'static str {
         "atrás"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str {
         use TimeUnit::*;
         if x == 1 {
@@ -29,6 +35,7 @@ impl Language for Portuguese {
                 Weeks => "semana",
                 Months => "mês",
                 Years => "ano",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,7 +49,151 @@ impl Language for Portuguese {
                 Weeks => "semanas",
                 Months => "meses",
                 Years => "anos",
+                Custom(c) => c.word(x),
             }
         }
     }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "um nanosegundo",
+            Microseconds => "um microsegundo",
+            Milliseconds => "um milisegundo",
+            Seconds => "um segundo",
+            Minutes => "um minuto",
+            Hours => "uma hora",
+            Days => "um dia",
+            Weeks => "uma semana",
+            Months => "um mês",
+            Years => "um ano",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("ontem"),
+            (TimeUnit::Days, 2) => Some("anteontem"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "hoje"
+    }
+    fn yesterday(&self) -> &'static str {
+        "ontem"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "amanhã"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} às {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "na próxima segunda-feira",
+                "na próxima terça-feira",
+                "na próxima quarta-feira",
+                "na próxima quinta-feira",
+                "na próxima sexta-feira",
+                "no próximo sábado",
+                "no próximo domingo",
+            ]
+        } else {
+            [
+                "na segunda-feira passada",
+                "na terça-feira passada",
+                "na quarta-feira passada",
+                "na quinta-feira passada",
+                "na sexta-feira passada",
+                "no sábado passado",
+                "no domingo passado",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "na semana passada",
+            (TimeUnit::Weeks, true) => "na próxima semana",
+            (TimeUnit::Months, false) => "no mês passado",
+            (TimeUnit::Months, true) => "no próximo mês",
+            (_, false) => "no ano passado",
+            (_, true) => "no próximo ano",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} d")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} de {month}"),
+            Some(year) => format!("{day} de {month} de {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "dia útil",
+            (TimeUnit::Days, _) => "dias úteis",
+            (_, 1) => "hora útil",
+            _ => "horas úteis",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Próximos",
+            Today => "Hoje",
+            Yesterday => "Ontem",
+            ThisWeek => "Esta semana",
+            LastWeek => "Semana passada",
+            ThisMonth => "Este mês",
+            Older => "Mais antigos",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "faltam"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("cerca de {items}"),
+            Qualifier::Over => format!("mais de {items}"),
+            Qualifier::Almost => format!("quase {items}"),
+            Qualifier::LessThan => format!("menos de {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "antes"),
+            Relation::After => ("", "depois"),
+            Relation::Overdue => ("com", "de atraso"),
+            Relation::DueIn => ("vence em", ""),
+            Relation::In => ("em", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("entre {from} e {to} {ago}"),
+            None => format!("entre {from} e {to}"),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use super::super::{Eta, Formatter};
+    use std::time::{Duration, Instant};
+    let day = Duration::from_secs(24 * 3600);
+    let start = Instant::now();
+    let mut eta = Eta::with_formatter(Formatter::with_language(Portuguese), start);
+    eta.update(0.25, start + day);
+    assert_eq!(eta.convert().unwrap(), "faltam cerca de 3 dias");
+    eta.approximate(false);
+    assert_eq!(eta.convert().unwrap(), "faltam 3 dias");
 }
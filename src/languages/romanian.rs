@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Romanian;
@@ -6,6 +6,9 @@ impl Language for Romanian {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "ro"
+    }
     fn too_low(&self) -> &'static str {
         "acum"
     }
@@ -29,6 +32,7 @@ impl Language for Romanian {
                 Weeks => "săptămână",
                 Months => "lună",
                 Years => "an",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,12 +46,146 @@ impl Language for Romanian {
                 Weeks => "săptămâni",
                 Months => "luni",
                 Years => "ani",
+                Custom(c) => c.word(x),
             }
         }
     }
     fn place_ago_before(&self) -> bool {
         true
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "o nanosecundă",
+            Microseconds => "o microsecundă",
+            Milliseconds => "o milisecundă",
+            Seconds => "o secundă",
+            Minutes => "un minut",
+            Hours => "o oră",
+            Days => "o zi",
+            Weeks => "o săptămână",
+            Months => "o lună",
+            Years => "un an",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("ieri"),
+            (TimeUnit::Days, 2) => Some("alaltăieri"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "azi"
+    }
+    fn yesterday(&self) -> &'static str {
+        "ieri"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "mâine"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} la {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "lunea viitoare",
+                "marțea viitoare",
+                "miercurea viitoare",
+                "joia viitoare",
+                "vinerea viitoare",
+                "sâmbăta viitoare",
+                "duminica viitoare",
+            ]
+        } else {
+            [
+                "lunea trecută",
+                "marțea trecută",
+                "miercurea trecută",
+                "joia trecută",
+                "vinerea trecută",
+                "sâmbăta trecută",
+                "duminica trecută",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "săptămâna trecută",
+            (TimeUnit::Weeks, true) => "săptămâna viitoare",
+            (TimeUnit::Months, false) => "luna trecută",
+            (TimeUnit::Months, true) => "luna viitoare",
+            (_, false) => "anul trecut",
+            (_, true) => "anul viitor",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} z")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "ian.", "feb.", "mar.", "apr.", "mai", "iun.", "iul.", "aug.", "sept.", "oct.", "nov.",
+            "dec.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "zi lucrătoare",
+            (TimeUnit::Days, _) => "zile lucrătoare",
+            (_, 1) => "oră lucrătoare",
+            _ => "ore lucrătoare",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "În curând",
+            Today => "Astăzi",
+            Yesterday => "Ieri",
+            ThisWeek => "Săptămâna aceasta",
+            LastWeek => "Săptămâna trecută",
+            ThisMonth => "Luna aceasta",
+            Older => "Mai devreme",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "încă"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("aproximativ {items}"),
+            Qualifier::Over => format!("peste {items}"),
+            Qualifier::Almost => format!("aproape {items}"),
+            Qualifier::LessThan => format!("mai puțin de {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("cu", "înainte"),
+            Relation::After => ("la", "după"),
+            Relation::Overdue => ("întârziat cu", ""),
+            Relation::DueIn => ("scadent în", ""),
+            Relation::In => ("peste", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{ago} între {from} și {to}"),
+            None => format!("între {from} și {to}"),
+        }
+    }
 }
 
 #[test]
@@ -1,4 +1,6 @@
-use super::super::{Language, TimeUnit};
+use super::super::{
+    Fraction, Language, Qualifier, Relation, TimeUnit, TimelineBucket, WordContext,
+};
 
 #[derive(Default)]
 pub struct Russian;
@@ -16,6 +18,7 @@ impl Russian {
             Weeks => "неделю",
             Months => "месяц",
             Years => "год",
+            Custom(c) => c.word(1),
         }
     }
     fn genitive(&self, tu: TimeUnit) -> &'static str {
@@ -31,6 +34,7 @@ impl Russian {
             Weeks => "недели",
             Months => "месяца",
             Years => "года",
+            Custom(c) => c.word(2),
         }
     }
     fn genitive_plural(&self, tu: TimeUnit) -> &'static str {
@@ -46,6 +50,23 @@ impl Russian {
             Weeks => "недель",
             Months => "месяцев",
             Years => "лет",
+            Custom(c) => c.word(2),
+        }
+    }
+    fn nominative(&self, tu: TimeUnit) -> &'static str {
+        use TimeUnit::*;
+        match tu {
+            Nanoseconds => "наносекунда",
+            Microseconds => "микросекунда",
+            Milliseconds => "миллисекунда",
+            Seconds => "секунда",
+            Minutes => "минута",
+            Hours => "час",
+            Days => "день",
+            Weeks => "неделя",
+            Months => "месяц",
+            Years => "год",
+            Custom(c) => c.word(1),
         }
     }
 }
@@ -53,6 +74,9 @@ impl Language for Russian {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "ru"
+    }
     fn too_low(&self) -> &'static str {
         "сейчас"
     }
@@ -62,6 +86,9 @@ impl Language for Russian {
     fn ago(&self) -> &'static str {
         "назад"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str {
         let last_two = x % 100;
         let last = x % 10;
@@ -77,13 +104,194 @@ impl Language for Russian {
             }
         }
     }
+    fn get_word_in(&self, tu: TimeUnit, x: u64, context: WordContext) -> &'static str {
+        let one = x % 10 == 1 && x % 100 != 11;
+        match (context.qualifier, context.relation) {
+            // the qualifier takes the genitive
+            (
+                Some(Qualifier::About | Qualifier::Over | Qualifier::LessThan | Qualifier::Between),
+                _,
+            ) if one => self.genitive(tu),
+            (
+                Some(Qualifier::About | Qualifier::Over | Qualifier::LessThan | Qualifier::Between),
+                _,
+            ) => self.genitive_plural(tu),
+            (None, Relation::Remaining) if one => self.nominative(tu),
+            _ => self.get_word(tu, x),
+        }
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        match tu {
+            TimeUnit::Custom(_) => None,
+            _ => Some(self.accusative(tu)),
+        }
+    }
+    fn one_unit_in(&self, tu: TimeUnit, context: WordContext) -> Option<&'static str> {
+        match (tu, context.qualifier) {
+            (TimeUnit::Custom(_), _) => None,
+            // "менее минуты"
+            (_, Some(Qualifier::LessThan)) => Some(self.genitive(tu)),
+            _ if context.relation == Relation::Ago => self.one_unit(tu),
+            _ => None,
+        }
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("вчера"),
+            (TimeUnit::Days, 2) => Some("позавчера"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "сегодня"
+    }
+    fn yesterday(&self) -> &'static str {
+        "вчера"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "завтра"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} в {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "в следующий понедельник",
+                "в следующий вторник",
+                "в следующую среду",
+                "в следующий четверг",
+                "в следующую пятницу",
+                "в следующую субботу",
+                "в следующее воскресенье",
+            ]
+        } else {
+            [
+                "в прошлый понедельник",
+                "в прошлый вторник",
+                "в прошлую среду",
+                "в прошлый четверг",
+                "в прошлую пятницу",
+                "в прошлую субботу",
+                "в прошлое воскресенье",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "на прошлой неделе",
+            (TimeUnit::Weeks, true) => "на следующей неделе",
+            (TimeUnit::Months, false) => "в прошлом месяце",
+            (TimeUnit::Months, true) => "в следующем месяце",
+            (_, false) => "в прошлом году",
+            (_, true) => "в следующем году",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} дн.")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "янв.",
+            "февр.",
+            "мар.",
+            "апр.",
+            "мая",
+            "июн.",
+            "июл.",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year} г."),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        let (one, few, many) = match tu {
+            TimeUnit::Days => ("рабочий день", "рабочих дня", "рабочих дней"),
+            _ => ("рабочий час", "рабочих часа", "рабочих часов"),
+        };
+        let (last, last_two) = (x % 10, x % 100);
+        if last == 1 && last_two != 11 {
+            one
+        } else if (2..=4).contains(&last) && !(12..=14).contains(&last_two) {
+            few
+        } else {
+            many
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Предстоящие",
+            Today => "Сегодня",
+            Yesterday => "Вчера",
+            ThisWeek => "На этой неделе",
+            LastWeek => "На прошлой неделе",
+            ThisMonth => "В этом месяце",
+            Older => "Ранее",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "осталось"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("около {items}"),
+            Qualifier::Over => format!("более {items}"),
+            Qualifier::Almost => format!("почти {items}"),
+            Qualifier::LessThan => format!("менее {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("за", "до"),
+            Relation::After => ("через", "после"),
+            Relation::Overdue => ("просрочено на", ""),
+            Relation::DueIn => ("через", ""),
+            Relation::In => ("через", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("от {from} до {to} {ago}"),
+            None => format!("от {from} до {to}"),
+        }
+    }
+    fn fraction(&self, tu: TimeUnit, x: u64, fraction: Fraction) -> Option<String> {
+        use TimeUnit::*;
+        let phrase = match (fraction, x, tu) {
+            (Fraction::Quarter, 0, Hours) => "четверть часа",
+            (Fraction::Half, 0, Minutes) => "полминуты",
+            (Fraction::Half, 0, Hours) => "полчаса",
+            (Fraction::Half, 0, Days) => "полдня",
+            (Fraction::Half, 0, Weeks) => "полнедели",
+            (Fraction::Half, 0, Months) => "полмесяца",
+            (Fraction::Half, 0, Years) => "полгода",
+            (Fraction::Half, 1, Minutes) => "полторы минуты",
+            (Fraction::Half, 1, Hours) => "полтора часа",
+            (Fraction::Half, 1, Days) => "полтора дня",
+            (Fraction::Half, 1, Weeks) => "полторы недели",
+            (Fraction::Half, 1, Months) => "полтора месяца",
+            (Fraction::Half, 1, Years) => "полтора года",
+            _ => return None,
+        };
+        Some(phrase.to_owned())
+    }
 }
 
 #[test]
 fn test() {
     use super::super::Formatter;
     use std::time::Duration;
-    let f = Formatter::with_language(Russian);
+    let mut f = Formatter::with_language(Russian);
     assert_eq!(f.convert(Duration::from_secs(60)), "1 минуту назад");
     assert_eq!(f.convert(Duration::from_secs(2)), "2 секунды назад");
     assert_eq!(f.convert(Duration::from_secs(5)), "5 секунд назад");
@@ -156,4 +364,65 @@ fn test() {
         f.convert(Duration::from_secs(111 * 3600 * 24 * 366)),
         "111 лет назад"
     );
+
+    f.idioms(true);
+    assert_eq!(f.convert(Duration::from_secs(60)), "минуту назад");
+    assert_eq!(f.convert(Duration::from_secs(3600)), "час назад");
+    assert_eq!(f.convert(Duration::from_secs(24 * 3600)), "вчера");
+    assert_eq!(f.convert(Duration::from_secs(2 * 24 * 3600)), "позавчера");
+    assert_eq!(f.convert(Duration::from_secs(3600 * 24 * 366)), "год назад");
+    assert_eq!(
+        f.convert(Duration::from_secs(21 * 3600 * 24 * 366)),
+        "21 год назад"
+    );
+
+    f.absolute_after(Duration::from_secs(7 * 24 * 3600));
+    let now = std::time::UNIX_EPOCH + Duration::from_secs(1_710_410_400);
+    let day = Duration::from_secs(24 * 3600);
+    assert_eq!(f.convert_system_time(now - 9 * day, now), "5 мар.");
+    assert_eq!(
+        f.convert_system_time(now - 375 * day, now),
+        "5 мар. 2023 г."
+    );
+
+    let start = std::time::Instant::now();
+    let mut eta = super::super::Eta::with_formatter(Formatter::with_language(Russian), start);
+    eta.update(0.5, start + Duration::from_secs(21 * 60));
+    assert_eq!(eta.convert().unwrap(), "осталось около 21 минуты");
+    eta.approximate(false);
+    assert_eq!(eta.convert().unwrap(), "осталось 21 минута");
+    eta.update(0.75, start + Duration::from_secs(63 * 30));
+    assert_eq!(eta.convert().unwrap(), "осталось 10 минут");
+
+    let mut f = Formatter::with_language(Russian);
+    f.relation(super::super::Relation::Before)
+        .reference("релиза");
+    assert_eq!(
+        f.convert(Duration::from_secs(3 * 86400)),
+        "за 3 дня до релиза"
+    );
+    f.relation(super::super::Relation::Overdue);
+    assert_eq!(f.convert(Duration::from_secs(60)), "просрочено на 1 минуту");
+
+    let mut f = Formatter::with_language(Russian);
+    f.qualifiers(true).min_unit(super::super::TimeUnit::Minutes);
+    let h = 3600;
+    assert_eq!(
+        f.convert(Duration::from_secs(3 * h + 60)),
+        "около 3 часов назад"
+    );
+    assert_eq!(
+        f.convert(Duration::from_secs(21 * h + 60)),
+        "около 21 часа назад"
+    );
+    assert_eq!(
+        f.convert(Duration::from_secs(3 * h + h / 2)),
+        "более 3 часов назад"
+    );
+    assert_eq!(
+        f.convert(Duration::from_secs(4 * h - 60)),
+        "почти 4 часа назад"
+    );
+    assert_eq!(f.convert(Duration::from_secs(h - 10)), "почти 1 час назад");
+    assert_eq!(f.convert(Duration::from_secs(30)), "менее минуты назад");
 }
@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Spanish;
@@ -6,6 +6,9 @@ impl Language for Spanish {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "es"
+    }
     fn too_low(&self) -> &'static str {
         "ahora"
     }
@@ -29,6 +32,7 @@ impl Language for Spanish {
                 Weeks => "semana",
                 Months => "mes",
                 Years => "año",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,12 +46,145 @@ impl Language for Spanish {
                 Weeks => "semanas",
                 Months => "meses",
                 Years => "años",
+                Custom(c) => c.word(x),
             }
         }
     }
     fn place_ago_before(&self) -> bool {
         true
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "un nanosegundo",
+            Microseconds => "un microsegundo",
+            Milliseconds => "un milisegundo",
+            Seconds => "un segundo",
+            Minutes => "un minuto",
+            Hours => "una hora",
+            Days => "un día",
+            Weeks => "una semana",
+            Months => "un mes",
+            Years => "un año",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("ayer"),
+            (TimeUnit::Days, 2) => Some("anteayer"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "hoy"
+    }
+    fn yesterday(&self) -> &'static str {
+        "ayer"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "mañana"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} a las {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "el próximo lunes",
+                "el próximo martes",
+                "el próximo miércoles",
+                "el próximo jueves",
+                "el próximo viernes",
+                "el próximo sábado",
+                "el próximo domingo",
+            ]
+        } else {
+            [
+                "el lunes pasado",
+                "el martes pasado",
+                "el miércoles pasado",
+                "el jueves pasado",
+                "el viernes pasado",
+                "el sábado pasado",
+                "el domingo pasado",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "la semana pasada",
+            (TimeUnit::Weeks, true) => "la próxima semana",
+            (TimeUnit::Months, false) => "el mes pasado",
+            (TimeUnit::Months, true) => "el próximo mes",
+            (_, false) => "el año pasado",
+            (_, true) => "el próximo año",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} d")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "día hábil",
+            (TimeUnit::Days, _) => "días hábiles",
+            (_, 1) => "hora laboral",
+            _ => "horas laborales",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Próximamente",
+            Today => "Hoy",
+            Yesterday => "Ayer",
+            ThisWeek => "Esta semana",
+            LastWeek => "La semana pasada",
+            ThisMonth => "Este mes",
+            Older => "Anteriores",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "quedan"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("aproximadamente {items}"),
+            Qualifier::Over => format!("más de {items}"),
+            Qualifier::Almost => format!("casi {items}"),
+            Qualifier::LessThan => format!("menos de {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "antes"),
+            Relation::After => ("", "después"),
+            Relation::Overdue => ("con", "de retraso"),
+            Relation::DueIn => ("vence en", ""),
+            Relation::In => ("en", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{ago} entre {from} y {to}"),
+            None => format!("entre {from} y {to}"),
+        }
+    }
 }
 
 #[test]
@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Swedish;
@@ -6,6 +6,9 @@ impl Language for Swedish {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "sv"
+    }
     fn too_low(&self) -> &'static str {
         "nu"
     }
@@ -29,6 +32,7 @@ impl Language for Swedish {
                 Weeks => "vecka",
                 Months => "månad",
                 Years => "år",
+                Custom(c) => c.word(1),
             }
         } else {
             match tu {
@@ -42,7 +46,138 @@ impl Language for Swedish {
                 Weeks => "veckor",
                 Months => "månader",
                 Years => "år",
+                Custom(c) => c.word(x),
             }
         }
     }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "en nanosekund",
+            Microseconds => "en mikrosekund",
+            Milliseconds => "en millisekund",
+            Seconds => "en sekund",
+            Minutes => "en minut",
+            Hours => "en timme",
+            Days => "en dag",
+            Weeks => "en vecka",
+            Months => "en månad",
+            Years => "ett år",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("i går"),
+            (TimeUnit::Days, 2) => Some("i förrgår"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "i dag"
+    }
+    fn yesterday(&self) -> &'static str {
+        "i går"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "i morgon"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} kl. {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "nästa måndag",
+                "nästa tisdag",
+                "nästa onsdag",
+                "nästa torsdag",
+                "nästa fredag",
+                "nästa lördag",
+                "nästa söndag",
+            ]
+        } else {
+            [
+                "i måndags",
+                "i tisdags",
+                "i onsdags",
+                "i torsdags",
+                "i fredags",
+                "i lördags",
+                "i söndags",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "förra veckan",
+            (TimeUnit::Weeks, true) => "nästa vecka",
+            (TimeUnit::Months, false) => "förra månaden",
+            (TimeUnit::Months, true) => "nästa månad",
+            (_, false) => "förra året",
+            (_, true) => "nästa år",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} d")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year}"),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "arbetsdag",
+            (TimeUnit::Days, _) => "arbetsdagar",
+            (_, 1) => "arbetstimme",
+            _ => "arbetstimmar",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Kommande",
+            Today => "Idag",
+            Yesterday => "Igår",
+            ThisWeek => "Den här veckan",
+            LastWeek => "Förra veckan",
+            ThisMonth => "Den här månaden",
+            Older => "Tidigare",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "kvar"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("ungefär {items}"),
+            Qualifier::Over => format!("över {items}"),
+            Qualifier::Almost => format!("nästan {items}"),
+            Qualifier::LessThan => format!("mindre än {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "före"),
+            Relation::After => ("", "efter"),
+            Relation::Overdue => ("", "försenad"),
+            Relation::DueIn => ("förfaller om", ""),
+            Relation::In => ("om", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("mellan {from} och {to} {ago}"),
+            None => format!("mellan {from} och {to}"),
+        }
+    }
 }
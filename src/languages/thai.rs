@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Thai;
@@ -6,6 +6,9 @@ impl Language for Thai {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "th"
+    }
     fn too_low(&self) -> &'static str {
         "ตอนนี้"
     }
@@ -15,6 +18,9 @@ impl Language for Thai {
     fn ago(&self) -> &'static str {
         "ที่แล้ว"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn override_space_near_ago(&self) -> &str {
         ""
     }
@@ -31,6 +37,129 @@ impl Language for Thai {
             Weeks => "สัปดาห์",
             Months => "เดือน",
             Years => "ปี",
+            Custom(c) => c.word(1),
+        }
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("เมื่อวาน"),
+            (TimeUnit::Days, 2) => Some("เมื่อวานซืน"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "วันนี้"
+    }
+    fn yesterday(&self) -> &'static str {
+        "เมื่อวาน"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "พรุ่งนี้"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} เวลา {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "วันจันทร์หน้า",
+                "วันอังคารหน้า",
+                "วันพุธหน้า",
+                "วันพฤหัสบดีหน้า",
+                "วันศุกร์หน้า",
+                "วันเสาร์หน้า",
+                "วันอาทิตย์หน้า",
+            ]
+        } else {
+            [
+                "วันจันทร์ที่แล้ว",
+                "วันอังคารที่แล้ว",
+                "วันพุธที่แล้ว",
+                "วันพฤหัสบดีที่แล้ว",
+                "วันศุกร์ที่แล้ว",
+                "วันเสาร์ที่แล้ว",
+                "วันอาทิตย์ที่แล้ว",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "สัปดาห์ที่แล้ว",
+            (TimeUnit::Weeks, true) => "สัปดาห์หน้า",
+            (TimeUnit::Months, false) => "เดือนที่แล้ว",
+            (TimeUnit::Months, true) => "เดือนหน้า",
+            (_, false) => "ปีที่แล้ว",
+            (_, true) => "ปีหน้า",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} วัน")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "ม.ค.",
+            "ก.พ.",
+            "มี.ค.",
+            "เม.ย.",
+            "พ.ค.",
+            "มิ.ย.",
+            "ก.ค.",
+            "ส.ค.",
+            "ก.ย.",
+            "ต.ค.",
+            "พ.ย.",
+            "ธ.ค.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {}", year + 543),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
+        match tu {
+            TimeUnit::Days => "วันทำการ",
+            _ => "ชั่วโมงทำการ",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "กำลังจะมาถึง",
+            Today => "วันนี้",
+            Yesterday => "เมื่อวาน",
+            ThisWeek => "สัปดาห์นี้",
+            LastWeek => "สัปดาห์ที่แล้ว",
+            ThisMonth => "เดือนนี้",
+            Older => "ก่อนหน้านี้",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "เหลืออีก"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("ประมาณ {items}"),
+            Qualifier::Over => format!("มากกว่า {items}"),
+            Qualifier::Almost => format!("เกือบ {items}"),
+            Qualifier::LessThan => format!("น้อยกว่า {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "ก่อน"),
+            Relation::After => ("", "หลัง"),
+            Relation::Overdue => ("", "เกินกำหนด"),
+            Relation::DueIn => ("", "ข้างหน้า"),
+            Relation::In => ("อีก", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("ระหว่าง {from} ถึง {to}{ago}"),
+            None => format!("ระหว่าง {from} ถึง {to}"),
         }
     }
 }
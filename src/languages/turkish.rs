@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket};
 
 #[derive(Default)]
 pub struct Turkish;
@@ -6,6 +6,9 @@ impl Language for Turkish {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "tr"
+    }
     fn too_low(&self) -> &'static str {
         "şimdi"
     }
@@ -28,8 +31,161 @@ impl Language for Turkish {
             Weeks => "hafta",
             Months => "ay",
             Years => "yıl",
+            Custom(c) => c.word(1),
+        }
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        use TimeUnit::*;
+        Some(match tu {
+            Nanoseconds => "bir nanosaniye",
+            Microseconds => "bir mikrosaniye",
+            Milliseconds => "bir milisaniye",
+            Seconds => "bir saniye",
+            Minutes => "bir dakika",
+            Hours => "bir saat",
+            Days => "bir gün",
+            Weeks => "bir hafta",
+            Months => "bir ay",
+            Years => "bir yıl",
+            Custom(_) => return None,
+        })
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("dün"),
+            (TimeUnit::Days, 2) => Some("evvelsi gün"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "bugün"
+    }
+    fn yesterday(&self) -> &'static str {
+        "dün"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "yarın"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} saat {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "gelecek pazartesi",
+                "gelecek salı",
+                "gelecek çarşamba",
+                "gelecek perşembe",
+                "gelecek cuma",
+                "gelecek cumartesi",
+                "gelecek pazar",
+            ]
+        } else {
+            [
+                "geçen pazartesi",
+                "geçen salı",
+                "geçen çarşamba",
+                "geçen perşembe",
+                "geçen cuma",
+                "geçen cumartesi",
+                "geçen pazar",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "geçen hafta",
+            (TimeUnit::Weeks, true) => "gelecek hafta",
+            (TimeUnit::Months, false) => "geçen ay",
+            (TimeUnit::Months, true) => "gelecek ay",
+            (_, false) => "geçen yıl",
+            (_, true) => "gelecek yıl",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} g")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year}"),
         }
     }
+    fn business_word(&self, tu: TimeUnit, _x: u64) -> &'static str {
+        match tu {
+            TimeUnit::Days => "iş günü",
+            _ => "iş saati",
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Yaklaşan",
+            Today => "Bugün",
+            Yesterday => "Dün",
+            ThisWeek => "Bu hafta",
+            LastWeek => "Geçen hafta",
+            ThisMonth => "Bu ay",
+            Older => "Daha eski",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "kaldı"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("yaklaşık {items}"),
+            Qualifier::Over => format!("{items} küsur"),
+            Qualifier::Almost => format!("neredeyse {items}"),
+            Qualifier::LessThan => format!("{} az", ablative(items)),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "önce"),
+            Relation::After => ("", "sonra"),
+            Relation::Overdue => ("", "gecikmeli"),
+            Relation::DueIn => ("", "içinde"),
+            Relation::In => ("", "sonra"),
+            _ => ("", ""),
+        }
+    }
+    fn place_reference_before(&self) -> bool {
+        true
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("{from} ila {to} {ago}"),
+            None => format!("{from} ile {to} arası"),
+        }
+    }
+}
+
+/// Ablative case of the last word, like "dakikadan" or "saatten", following vowel harmony
+fn ablative(items: &str) -> String {
+    // "saat" is an exception to vowel harmony
+    let front = items.ends_with("saat")
+        || items
+            .chars()
+            .rev()
+            .find(|c| "aıoueiöü".contains(*c))
+            .map_or(false, |c| "eiöü".contains(c));
+    let voiceless = items
+        .chars()
+        .last()
+        .map_or(false, |c| "çfhkpsşt".contains(c));
+    let suffix = match (voiceless, front) {
+        (false, false) => "dan",
+        (false, true) => "den",
+        (true, false) => "tan",
+        (true, true) => "ten",
+    };
+    format!("{items}{suffix}")
 }
 
 #[test]
@@ -83,4 +239,11 @@ fn test() {
         f.convert(Duration::from_secs(111 * 3600 * 24 * 366)),
         "111 yıl önce"
     );
+    let mut f = Formatter::with_language(Turkish);
+    f.qualifiers(true).min_unit(super::super::TimeUnit::Hours);
+    assert_eq!(f.convert(Duration::from_secs(60)), "bir saatten az önce");
+    f.min_unit(super::super::TimeUnit::Days);
+    assert_eq!(f.convert(Duration::from_secs(60)), "bir günden az önce");
+    f.min_unit(super::super::TimeUnit::Minutes);
+    assert_eq!(f.convert(Duration::from_secs(30)), "bir dakikadan az önce");
 }
@@ -1,4 +1,4 @@
-use super::super::{Language, TimeUnit};
+use super::super::{Language, Qualifier, Relation, TimeUnit, TimelineBucket, WordContext};
 
 #[derive(Default)]
 pub struct Ukrainian;
@@ -16,6 +16,7 @@ impl Ukrainian {
             Weeks => "тиждень",
             Months => "місяць",
             Years => "рік",
+            Custom(c) => c.word(1),
         }
     }
     fn genitive(&self, tu: TimeUnit) -> &'static str {
@@ -31,6 +32,7 @@ impl Ukrainian {
             Weeks => "тижня",
             Months => "місяця",
             Years => "роки",
+            Custom(c) => c.word(2),
         }
     }
     fn genitive_plural(&self, tu: TimeUnit) -> &'static str {
@@ -46,6 +48,39 @@ impl Ukrainian {
             Weeks => "тижнів",
             Months => "місяців",
             Years => "років",
+            Custom(c) => c.word(2),
+        }
+    }
+    fn nominative(&self, tu: TimeUnit) -> &'static str {
+        use TimeUnit::*;
+        match tu {
+            Nanoseconds => "наносекунда",
+            Microseconds => "мікросекунда",
+            Milliseconds => "мілісекунда",
+            Seconds => "секунда",
+            Minutes => "хвилина",
+            Hours => "година",
+            Days => "день",
+            Weeks => "тиждень",
+            Months => "місяць",
+            Years => "рік",
+            Custom(c) => c.word(1),
+        }
+    }
+    fn genitive_singular(&self, tu: TimeUnit) -> &'static str {
+        use TimeUnit::*;
+        match tu {
+            Nanoseconds => "наносекунди",
+            Microseconds => "мікросекунди",
+            Milliseconds => "мілісекунди",
+            Seconds => "секунди",
+            Minutes => "хвилини",
+            Hours => "години",
+            Days => "дня",
+            Weeks => "тижня",
+            Months => "місяця",
+            Years => "року",
+            Custom(c) => c.word(2),
         }
     }
 }
@@ -53,6 +88,9 @@ impl Language for Ukrainian {
     fn clone_boxed(&self) -> super::super::BoxedLanguage {
         Box::new(Self {})
     }
+    fn language_tag(&self) -> &'static str {
+        "uk"
+    }
     fn too_low(&self) -> &'static str {
         "зараз"
     }
@@ -62,6 +100,9 @@ impl Language for Ukrainian {
     fn ago(&self) -> &'static str {
         "тому"
     }
+    fn place_remaining_before(&self) -> bool {
+        true
+    }
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str {
         let last_two = x % 100;
         let last = x % 10;
@@ -78,6 +119,152 @@ impl Language for Ukrainian {
             unreachable!()
         }
     }
+    fn get_word_in(&self, tu: TimeUnit, x: u64, context: WordContext) -> &'static str {
+        let one = x % 10 == 1 && x % 100 != 11;
+        match (context.qualifier, context.relation) {
+            // the qualifier takes the genitive
+            (Some(Qualifier::About | Qualifier::Between), _) if one => self.genitive_singular(tu),
+            (Some(Qualifier::About | Qualifier::Between), _) => self.genitive_plural(tu),
+            (None, Relation::Remaining) if one => self.nominative(tu),
+            _ => self.get_word(tu, x),
+        }
+    }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        match tu {
+            TimeUnit::Custom(_) => None,
+            _ => Some(self.accusative(tu)),
+        }
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => Some("вчора"),
+            (TimeUnit::Days, 2) => Some("позавчора"),
+            _ => None,
+        }
+    }
+    fn today(&self) -> &'static str {
+        "сьогодні"
+    }
+    fn yesterday(&self) -> &'static str {
+        "вчора"
+    }
+    fn tomorrow(&self) -> &'static str {
+        "завтра"
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} о {time}")
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "наступного понеділка",
+                "наступного вівторка",
+                "наступної середи",
+                "наступного четверга",
+                "наступної п'ятниці",
+                "наступної суботи",
+                "наступної неділі",
+            ]
+        } else {
+            [
+                "минулого понеділка",
+                "минулого вівторка",
+                "минулої середи",
+                "минулого четверга",
+                "минулої п'ятниці",
+                "минулої суботи",
+                "минулої неділі",
+            ]
+        };
+        names[weekday as usize]
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "минулого тижня",
+            (TimeUnit::Weeks, true) => "наступного тижня",
+            (TimeUnit::Months, false) => "минулого місяця",
+            (TimeUnit::Months, true) => "наступного місяця",
+            (_, false) => "минулого року",
+            (_, true) => "наступного року",
+        }
+    }
+    fn short_days(&self, x: u64) -> String {
+        format!("{x} дн.")
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "січ.",
+            "лют.",
+            "бер.",
+            "квіт.",
+            "трав.",
+            "черв.",
+            "лип.",
+            "серп.",
+            "вер.",
+            "жовт.",
+            "листоп.",
+            "груд.",
+        ][month as usize - 1];
+        match year {
+            None => format!("{day} {month}"),
+            Some(year) => format!("{day} {month} {year} р."),
+        }
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        let (one, few, many) = match tu {
+            TimeUnit::Days => ("робочий день", "робочі дні", "робочих днів"),
+            _ => ("робочу годину", "робочі години", "робочих годин"),
+        };
+        let (last, last_two) = (x % 10, x % 100);
+        if last == 1 && last_two != 11 {
+            one
+        } else if (2..=4).contains(&last) && !(12..=14).contains(&last_two) {
+            few
+        } else {
+            many
+        }
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Майбутні",
+            Today => "Сьогодні",
+            Yesterday => "Учора",
+            ThisWeek => "Цього тижня",
+            LastWeek => "Минулого тижня",
+            ThisMonth => "Цього місяця",
+            Older => "Раніше",
+        }
+    }
+    fn remaining(&self) -> &'static str {
+        "залишилося"
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("близько {items}"),
+            Qualifier::Over => format!("понад {items}"),
+            Qualifier::Almost => format!("майже {items}"),
+            Qualifier::LessThan => format!("менше ніж {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("за", "до"),
+            Relation::After => ("через", "після"),
+            Relation::Overdue => ("прострочено на", ""),
+            Relation::DueIn => ("через", ""),
+            Relation::In => ("через", ""),
+            _ => ("", ""),
+        }
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("від {from} до {to} {ago}"),
+            None => format!("від {from} до {to}"),
+        }
+    }
 }
 
 #[test]
@@ -1,7 +1,9 @@
 #![deny(missing_docs)]
 //! Given a Duration, lossily format it like in 'N days ago'.
 //!
-//! Parsing it back to Duration is not supported yet (See [`chrono-english`] crate).
+//! Parsing it back to Duration is not supported yet (See [`chrono-english`] crate),
+//! but machine-readable formats like ISO 8601 durations or Go-style "1h30m" can be formatted and parsed
+//! with the [`iso8601`] and [`compact`] modules, PostgreSQL interval text with the [`postgres`] module.
 //!
 //! Multiple languages are supported though `Language` trait.
 //! Enable `isolang` feature to gain support of getting Language impl from
@@ -18,6 +20,9 @@
 //!
 //! [`chrono-english`]:https://docs.rs/chrono-english
 //! [`Formatter`]:struct.Formatter.html
+//! [`iso8601`]:iso8601/index.html
+//! [`compact`]:compact/index.html
+//! [`postgres`]:postgres/index.html
 
 use std::time::Duration;
 
@@ -40,10 +45,20 @@ pub trait Language {
     /// Get word representing the given time unit, for using with `x` number
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str;
 
+    /// Word for the unit in the given grammatical context, for languages where it differs
+    /// from the form used with `ago`, like "около 3 минут"
+    fn get_word_in(&self, tu: TimeUnit, x: u64, _context: WordContext) -> &'static str {
+        self.get_word(tu, x)
+    }
+
     /// For German and such
     fn place_ago_before(&self) -> bool {
         false
     }
+    /// Put `remaining` before the items, like "noch 3 Minuten"
+    fn place_remaining_before(&self) -> bool {
+        false
+    }
     /// For Thai and such
     fn override_space_near_ago(&self) -> &str {
         " "
@@ -59,14 +74,201 @@ pub trait Language {
         " "
     }
 
+    /// Phrase for exactly one unit to use instead of "1 unit" in idiomatic mode, like
+    /// "an hour" or "einer Stunde" (in the case required by `ago`).
+    fn one_unit(&self, _: TimeUnit) -> Option<&'static str> {
+        None
+    }
+    /// Dedicated phrase replacing the whole "x units ago" in idiomatic mode,
+    /// like "yesterday" or "позавчера"
+    fn idiom(&self, _: TimeUnit, _x: u64) -> Option<&'static str> {
+        None
+    }
+    /// Phrase for a fraction of one unit in the case required by `ago`, like "half an hour"
+    /// for `x` being 0, or for `x` units and a half, like "an hour and a half" or "полтора часа".
+    /// Used instead of the final item in the fraction mode, see `Formatter::fractions`.
+    fn fraction(&self, _tu: TimeUnit, _x: u64, _fraction: Fraction) -> Option<String> {
+        None
+    }
+    /// Phrase for exactly one unit in the given grammatical context, like "a minute"
+    /// in "less than a minute". Defaults to `one_unit` in the context of `ago`.
+    fn one_unit_in(&self, tu: TimeUnit, context: WordContext) -> Option<&'static str> {
+        match context.relation {
+            Relation::Ago => self.one_unit(tu),
+            _ => None,
+        }
+    }
+
+    /// Word for the current calendar day, like "today"
+    fn today(&self) -> &'static str {
+        "today"
+    }
+    /// Word for the previous calendar day, like "yesterday"
+    fn yesterday(&self) -> &'static str {
+        "yesterday"
+    }
+    /// Word for the next calendar day, like "tomorrow"
+    fn tomorrow(&self) -> &'static str {
+        "tomorrow"
+    }
+    /// Combine a day word like "yesterday" with a time like "14:05"
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        format!("{day} at {time}")
+    }
+    /// Phrase for a weekday of the past or upcoming days, like "last Tuesday".
+    /// `weekday` is the number of days since Monday.
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        let names = if next {
+            [
+                "next Monday",
+                "next Tuesday",
+                "next Wednesday",
+                "next Thursday",
+                "next Friday",
+                "next Saturday",
+                "next Sunday",
+            ]
+        } else {
+            [
+                "last Monday",
+                "last Tuesday",
+                "last Wednesday",
+                "last Thursday",
+                "last Friday",
+                "last Saturday",
+                "last Sunday",
+            ]
+        };
+        names[weekday as usize]
+    }
+    /// Phrase for the previous or the next calendar period, like "last month".
+    /// Only `Weeks`, `Months` and `Years` are used.
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        match (tu, next) {
+            (TimeUnit::Weeks, false) => "last week",
+            (TimeUnit::Weeks, true) => "next week",
+            (TimeUnit::Months, false) => "last month",
+            (TimeUnit::Months, true) => "next month",
+            (_, false) => "last year",
+            (_, true) => "next year",
+        }
+    }
+
+    /// Abbreviated day count for clock-style output, like "3d" in "3d 04:05:06"
+    fn short_days(&self, x: u64) -> String {
+        format!("{x}d")
+    }
+
+    /// Short absolute date like "Mar 5" or, if `year` is specified, "Mar 5, 2019".
+    /// `month` is from 1 to 12.
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        let month = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ][month as usize - 1];
+        match year {
+            None => format!("{month} {day}"),
+            Some(year) => format!("{month} {day}, {year}"),
+        }
+    }
+
+    /// Word for `x` business days or working hours, for `tu` being `Days` or `Hours`
+    /// respectively, like "business days" in "3 business days ago"
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        match (tu, x) {
+            (TimeUnit::Days, 1) => "business day",
+            (TimeUnit::Days, _) => "business days",
+            (_, 1) => "working hour",
+            _ => "working hours",
+        }
+    }
+
+    /// Header for a section of a timeline, like "Yesterday" or "Earlier"
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        use TimelineBucket::*;
+        match bucket {
+            Upcoming => "Upcoming",
+            Today => "Today",
+            Yesterday => "Yesterday",
+            ThisWeek => "This week",
+            LastWeek => "Last week",
+            ThisMonth => "This month",
+            Older => "Earlier",
+        }
+    }
+
+    /// Word for the time left, like "remaining" in "3 minutes remaining"
+    fn remaining(&self) -> &'static str {
+        "remaining"
+    }
+    /// Approximate amount like "about 3 minutes" for `items` being "3 minutes"
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        match q {
+            Qualifier::About => format!("about {items}"),
+            Qualifier::Over => format!("over {items}"),
+            Qualifier::Almost => format!("almost {items}"),
+            Qualifier::LessThan => format!("less than {items}"),
+            Qualifier::Between => items.to_owned(),
+        }
+    }
+    /// Words put before and after the items for relations other than `Ago` and `Remaining`,
+    /// like `("overdue by", "")` or `("за", "до")` for "за 3 дня до релиза"
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        match r {
+            Relation::Before => ("", "before"),
+            Relation::After => ("", "after"),
+            Relation::Overdue => ("overdue by", ""),
+            Relation::DueIn => ("due in", ""),
+            Relation::In => ("in", ""),
+            _ => ("", ""),
+        }
+    }
+    /// Put the reference of `Before` and `After` relations before the phrase, like "リリースの3日前"
+    fn place_reference_before(&self) -> bool {
+        false
+    }
+
+    /// Separator of the bounds of a range, like "–" in "2–3 hours ago" for `shared_unit`
+    /// and " – " in "45 minutes – 2 hours ago" otherwise
+    fn range_separator(&self, shared_unit: bool) -> &'static str {
+        if shared_unit {
+            "–"
+        } else {
+            " – "
+        }
+    }
+    /// Range in words like "between 2 and 3 days" for `from` being "2" and `to` being "3 days".
+    /// For the `Ago` relation, `ago` is the word to place into the phrase, like "vor 2 bis 3 Tagen".
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        match ago {
+            Some(ago) => format!("between {from} and {to} {ago}"),
+            None => format!("between {from} and {to}"),
+        }
+    }
+
     /// Make a dynamic copy of this language
     fn clone_boxed(&self) -> BoxedLanguage;
+
+    /// BCP 47 tag of the language, like "en" or "pt"
+    fn language_tag(&self) -> &'static str {
+        "en"
+    }
+
+    /// Whether the language is written from right to left
+    fn right_to_left(&self) -> bool {
+        false
+    }
 }
 
 impl Language for BoxedLanguage {
     fn clone_boxed(&self) -> BoxedLanguage {
         (**self).clone_boxed()
     }
+    fn language_tag(&self) -> &'static str {
+        (**self).language_tag()
+    }
+    fn right_to_left(&self) -> bool {
+        (**self).right_to_left()
+    }
     fn too_low(&self) -> &'static str {
         (**self).too_low()
     }
@@ -79,9 +281,21 @@ impl Language for BoxedLanguage {
     fn get_word(&self, tu: TimeUnit, x: u64) -> &'static str {
         (**self).get_word(tu, x)
     }
+    fn get_word_in(&self, tu: TimeUnit, x: u64, context: WordContext) -> &'static str {
+        (**self).get_word_in(tu, x, context)
+    }
     fn place_ago_before(&self) -> bool {
         (**self).place_ago_before()
     }
+    fn place_remaining_before(&self) -> bool {
+        (**self).place_remaining_before()
+    }
+    fn fraction(&self, tu: TimeUnit, x: u64, fraction: Fraction) -> Option<String> {
+        (**self).fraction(tu, x, fraction)
+    }
+    fn one_unit_in(&self, tu: TimeUnit, context: WordContext) -> Option<&'static str> {
+        (**self).one_unit_in(tu, context)
+    }
     fn override_space_near_ago(&self) -> &str {
         (**self).override_space_near_ago()
     }
@@ -94,6 +308,60 @@ impl Language for BoxedLanguage {
     fn between_value_and_word(&self) -> &str {
         (**self).between_value_and_word()
     }
+    fn one_unit(&self, tu: TimeUnit) -> Option<&'static str> {
+        (**self).one_unit(tu)
+    }
+    fn idiom(&self, tu: TimeUnit, x: u64) -> Option<&'static str> {
+        (**self).idiom(tu, x)
+    }
+    fn today(&self) -> &'static str {
+        (**self).today()
+    }
+    fn yesterday(&self) -> &'static str {
+        (**self).yesterday()
+    }
+    fn tomorrow(&self) -> &'static str {
+        (**self).tomorrow()
+    }
+    fn day_at_time(&self, day: &str, time: &str) -> String {
+        (**self).day_at_time(day, time)
+    }
+    fn weekday_phrase(&self, weekday: u32, next: bool) -> &'static str {
+        (**self).weekday_phrase(weekday, next)
+    }
+    fn period_phrase(&self, tu: TimeUnit, next: bool) -> &'static str {
+        (**self).period_phrase(tu, next)
+    }
+    fn short_days(&self, x: u64) -> String {
+        (**self).short_days(x)
+    }
+    fn absolute_date(&self, day: u32, month: u32, year: Option<i32>) -> String {
+        (**self).absolute_date(day, month, year)
+    }
+    fn business_word(&self, tu: TimeUnit, x: u64) -> &'static str {
+        (**self).business_word(tu, x)
+    }
+    fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        (**self).timeline_label(bucket)
+    }
+    fn remaining(&self) -> &'static str {
+        (**self).remaining()
+    }
+    fn qualify(&self, q: Qualifier, items: &str) -> String {
+        (**self).qualify(q, items)
+    }
+    fn relation_words(&self, r: Relation) -> (&'static str, &'static str) {
+        (**self).relation_words(r)
+    }
+    fn place_reference_before(&self) -> bool {
+        (**self).place_reference_before()
+    }
+    fn range_separator(&self, shared_unit: bool) -> &'static str {
+        (**self).range_separator(shared_unit)
+    }
+    fn range_between(&self, from: &str, to: &str, ago: Option<&str>) -> String {
+        (**self).range_between(from, to, ago)
+    }
 }
 
 /// Dynamic version of the `Language` trait
@@ -128,10 +396,74 @@ pub mod languages {
 
 pub use languages::english::English;
 
+#[cfg(feature = "chrono")]
+mod calendar;
+
+#[cfg(feature = "html")]
+mod html;
+#[cfg(feature = "html")]
+pub use html::HtmlFormatter;
+
+#[cfg(feature = "chrono")]
+mod hybrid;
+#[cfg(feature = "chrono")]
+pub use hybrid::HybridPosition;
+
+mod hysteresis;
+pub use hysteresis::Hysteresis;
+
+mod calendar_model;
+pub use calendar_model::CalendarModel;
+
+mod absolute;
+
+mod civil;
+pub use civil::CivilCalendar;
+
+mod clock;
+pub use clock::Clock;
+
+mod parse;
+pub use parse::{ParseError, ParseErrorKind};
+
+mod schedule;
+pub use schedule::Changes;
+
+mod timeline;
+pub use timeline::TimelineBucket;
+
+mod relation;
+pub use relation::{Qualifier, Relation, WordContext};
+
+mod eta;
+pub use eta::Eta;
+
+mod rails;
+
+mod range;
+
+mod fraction;
+pub use fraction::Fraction;
+
+#[cfg(feature = "chrono")]
+mod business;
+#[cfg(feature = "chrono")]
+pub use business::BusinessCalendar;
+
+#[cfg(feature = "chrono")]
+mod date;
+
+pub mod compact;
+pub mod iso8601;
+pub mod postgres;
+
 /// Various units of time to specify as maximum or minimum.
 /// Note that calculations are approximate, not calendar-based.
+///
+/// Units are ordered by their length, so `Custom` units fit between the built-in ones.
 #[allow(missing_docs)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum TimeUnit {
     Nanoseconds,
     Microseconds,
@@ -143,8 +475,25 @@ pub enum TimeUnit {
     Weeks,
     Months,
     Years,
+    /// User-defined unit like quarter, decade or sprint. See [`CustomUnit`].
+    ///
+    /// [`CustomUnit`]:struct.CustomUnit.html
+    Custom(CustomUnit),
 }
 
+const BUILTIN_UNITS: [TimeUnit; 10] = [
+    TimeUnit::Nanoseconds,
+    TimeUnit::Microseconds,
+    TimeUnit::Milliseconds,
+    TimeUnit::Seconds,
+    TimeUnit::Minutes,
+    TimeUnit::Hours,
+    TimeUnit::Days,
+    TimeUnit::Weeks,
+    TimeUnit::Months,
+    TimeUnit::Years,
+];
+
 impl TimeUnit {
     /// Get `std::time::Duration` corresponding to minimum duration that is representable by this time unit.
     pub fn min_duration(&self) -> Duration {
@@ -160,10 +509,17 @@ impl TimeUnit {
             Weeks => Duration::new(7 * 24 * 60 * 60, 0),
             Months => Duration::new(S_IN_MNTH, 0),
             Years => Duration::new(S_IN_MNTH * 12, 0),
+            Custom(c) => c.length(),
         }
     }
 
     /// "Upgrade" minutes to hours, hours to days and so on.
+    ///
+    /// Only built-in units are considered as the result, custom units are
+    /// upgraded to the nearest longer built-in unit. Use [`Formatter::bigger_unit`]
+    /// to also take custom units registered in a formatter into account.
+    ///
+    /// [`Formatter::bigger_unit`]:struct.Formatter.html#method.bigger_unit
     pub fn bigger_unit(&self) -> Option<TimeUnit> {
         use TimeUnit::*;
         match *self {
@@ -177,10 +533,13 @@ impl TimeUnit {
             Weeks => Some(Months),
             Months => Some(Years),
             Years => None,
+            Custom(_) => BUILTIN_UNITS.iter().copied().find(|x| x > self),
         }
     }
 
     /// "Downgrade" weeks to days, seconds to milliseconds and so on.
+    ///
+    /// Custom units are downgraded to the nearest shorter built-in unit.
     pub fn smaller_unit(&self) -> Option<TimeUnit> {
         use TimeUnit::*;
         match *self {
@@ -194,6 +553,88 @@ impl TimeUnit {
             Weeks => Some(Days),
             Months => Some(Weeks),
             Years => Some(Months),
+            Custom(_) => BUILTIN_UNITS.iter().rev().copied().find(|x| x < self),
+        }
+    }
+}
+
+impl Ord for TimeUnit {
+    fn cmp(&self, other: &TimeUnit) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        self.min_duration()
+            .cmp(&other.min_duration())
+            .then_with(|| match (self, other) {
+                (TimeUnit::Custom(a), TimeUnit::Custom(b)) => {
+                    (a.singular, a.plural).cmp(&(b.singular, b.plural))
+                }
+                (TimeUnit::Custom(_), _) => Ordering::Greater,
+                (_, TimeUnit::Custom(_)) => Ordering::Less,
+                // Built-in units all have distinct lengths
+                _ => Ordering::Equal,
+            })
+    }
+}
+
+impl PartialOrd for TimeUnit {
+    fn partial_cmp(&self, other: &TimeUnit) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// User-defined time unit like a quarter, a decade or a 14-day sprint.
+///
+/// It has a length (whole number of seconds) and fallback words that are used when
+/// the language has no better ones. Enable it in a formatter with [`Formatter::custom_unit`],
+/// optionally specifying the words for the formatter's language.
+///
+/// ```
+/// use std::time::Duration;
+/// use timeago::{CustomUnit, TimeUnit};
+/// const SPRINT: CustomUnit = CustomUnit::new("sprint", "sprints", Duration::from_secs(14 * 86400));
+/// let mut f = timeago::Formatter::new();
+/// f.custom_unit(SPRINT);
+/// assert_eq!(f.convert(Duration::from_secs(20 * 86400)), "1 sprint ago");
+/// f.num_items(2);
+/// assert_eq!(f.convert(Duration::from_secs(29 * 86400)), "2 sprints 1 day ago");
+/// assert!(TimeUnit::Custom(SPRINT) > TimeUnit::Weeks);
+/// assert!(TimeUnit::Custom(SPRINT) < TimeUnit::Months);
+/// ```
+///
+/// [`Formatter::custom_unit`]:struct.Formatter.html#method.custom_unit
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CustomUnit {
+    singular: &'static str,
+    plural: &'static str,
+    secs: u64,
+}
+
+impl CustomUnit {
+    /// Define a unit of given length with fallback words for one and for many units.
+    ///
+    /// Panics if `length` is shorter than a second or is not a whole number of seconds.
+    pub const fn new(singular: &'static str, plural: &'static str, length: Duration) -> CustomUnit {
+        assert!(
+            length.as_secs() > 0 && length.subsec_nanos() == 0,
+            "custom unit length must be a whole number of seconds"
+        );
+        CustomUnit {
+            singular,
+            plural,
+            secs: length.as_secs(),
+        }
+    }
+
+    /// Length of one such unit
+    pub fn length(&self) -> Duration {
+        Duration::from_secs(self.secs)
+    }
+
+    /// Fallback word for `x` units, using English plural rule
+    pub fn word(&self, x: u64) -> &'static str {
+        if x == 1 {
+            self.singular
+        } else {
+            self.plural
         }
     }
 }
@@ -213,8 +654,64 @@ pub struct Formatter<L: Language = English> {
     too_high: Option<&'static str>,
     ago: Option<&'static str>,
     max_duration: Duration,
+    custom_units: Vec<(CustomUnit, Option<UnitWords>)>,
+    thresholds: Vec<(TimeUnit, Duration)>,
+    idioms: bool,
+    chunking: Chunking,
+    calendar: CalendarModel,
+    civil_calendar: Option<CivilCalendar>,
+    absolute_after: Option<Duration>,
+    timeline_buckets: Vec<TimelineBucket>,
+    relation: Relation,
+    reference: Option<&'static str>,
+    qualifiers: bool,
+    range_words: bool,
+    fractions: bool,
+}
+
+/// How units for the items after the first one are chosen when `num_items` is more than 1.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Chunking {
+    /// Each item uses the dominant unit of what remains, skipping zero units,
+    /// like "1 hour 5 seconds". This is the default.
+    Free,
+    /// Items come from consecutive units, stopping at the first zero, like "1 hour".
+    /// This way `num_items` means precision relative to the first unit.
+    Adjacent,
+    /// Items come from consecutive units, with zero items printed, like "1 hour 0 minutes".
+    AdjacentWithZeros,
+}
+
+/// Reason why `Formatter::try_convert` could not format a duration
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ConvertError {
+    /// Duration is too short to be represented with `min_unit`, formatted as "now" by `convert`
+    TooLow,
+    /// Duration is longer than `max_duration`, formatted as "old" by `convert`
+    TooHigh,
+    /// Number of the given units does not fit into `u64`, like nanoseconds in 600 years
+    Overflow(TimeUnit),
+    /// The end of the timespan comes before its start
+    Negative,
+}
+
+impl std::fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConvertError::TooLow => write!(f, "duration is too short for the minimal unit"),
+            ConvertError::TooHigh => write!(f, "duration exceeds the maximal duration"),
+            ConvertError::Overflow(tu) => write!(f, "too many {tu:?} to count"),
+            ConvertError::Negative => write!(f, "timespan is negative"),
+        }
+    }
 }
 
+impl std::error::Error for ConvertError {}
+
+/// Function giving a word for the number of some custom units
+type UnitWords = fn(u64) -> &'static str;
+
 impl Default for Formatter {
     fn default() -> Self {
         Self::new()
@@ -241,6 +738,19 @@ impl Clone for Formatter<BoxedLanguage> {
             too_high: self.too_high,
             ago: self.ago,
             max_duration: self.max_duration,
+            custom_units: self.custom_units.clone(),
+            thresholds: self.thresholds.clone(),
+            idioms: self.idioms,
+            chunking: self.chunking,
+            calendar: self.calendar,
+            civil_calendar: self.civil_calendar,
+            absolute_after: self.absolute_after,
+            timeline_buckets: self.timeline_buckets.clone(),
+            relation: self.relation,
+            reference: self.reference,
+            qualifiers: self.qualifiers,
+            range_words: self.range_words,
+            fractions: self.fractions,
         }
     }
 }
@@ -259,6 +769,19 @@ impl<L: Language> Formatter<L> {
             too_high: None,
             ago: None,
             max_duration: Duration::new(u64::MAX, 999_999_999),
+            custom_units: Vec::new(),
+            thresholds: Vec::new(),
+            idioms: false,
+            chunking: Chunking::Free,
+            calendar: CalendarModel::DEFAULT,
+            civil_calendar: None,
+            absolute_after: None,
+            timeline_buckets: TimelineBucket::ALL.to_vec(),
+            relation: Relation::Ago,
+            reference: None,
+            qualifiers: false,
+            range_words: false,
+            fractions: false,
         }
     }
 
@@ -282,6 +805,74 @@ impl<L: Language> Formatter<L> {
         self
     }
 
+    /// Set how units are chosen for items after the first one. See [`Chunking`].
+    /// ```
+    /// use timeago::Chunking;
+    /// let mut f = timeago::Formatter::new();
+    /// f.num_items(2);
+    /// let d = std::time::Duration::from_secs(3600 + 5);
+    /// assert_eq!(f.convert(d), "1 hour 5 seconds ago");
+    /// f.chunking(Chunking::Adjacent);
+    /// assert_eq!(f.convert(d), "1 hour ago");
+    /// f.chunking(Chunking::AdjacentWithZeros);
+    /// assert_eq!(f.convert(d), "1 hour 0 minutes ago");
+    /// f.num_items(3);
+    /// assert_eq!(f.convert(d), "1 hour 0 minutes 5 seconds ago");
+    /// ```
+    ///
+    /// [`Chunking`]:enum.Chunking.html
+    pub fn chunking(&mut self, x: Chunking) -> &mut Self {
+        self.chunking = x;
+        self
+    }
+
+    /// Set lengths of months and years, see [`CalendarModel`]. Default is `CalendarModel::DEFAULT`.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::{CalendarModel, TimeUnit};
+    /// let mut f = timeago::Formatter::new();
+    /// let d = Duration::from_secs(360 * 86400);
+    /// assert_eq!(f.convert(d), "11 months ago");
+    /// f.calendar(CalendarModel::THIRTY_360);
+    /// assert_eq!(f.convert(d), "1 year ago");
+    /// f.calendar(CalendarModel::JULIAN).num_items(2);
+    /// assert_eq!(f.convert(d), "11 months 3 weeks ago");
+    /// ```
+    ///
+    /// [`CalendarModel`]:struct.CalendarModel.html
+    pub fn calendar(&mut self, x: CalendarModel) -> &mut Self {
+        self.calendar = x;
+        self
+    }
+
+    /// Count months and years by the given civil calendar in `convert_chrono`,
+    /// instead of converting the timespan to a `Duration` first.
+    /// This does not depend on the language used for the output.
+    ///
+    /// Whole months and years are counted between the local dates and times in the time zone of `to`,
+    /// the rest is formatted as usual, but using units shorter than a month.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::{TimeZone, Utc};
+    /// use timeago::CivilCalendar;
+    /// let mut f = timeago::Formatter::new();
+    /// f.num_items(2);
+    /// // 1 Ramadan 1444 and 1 Shawwal 1445
+    /// let from = Utc.with_ymd_and_hms(2023, 3, 23, 12, 0, 0).unwrap();
+    /// let to = Utc.with_ymd_and_hms(2024, 4, 10, 12, 0, 0).unwrap();
+    /// assert_eq!(f.convert_chrono(from, to), "1 year 2 weeks ago");
+    /// f.civil_calendar(CivilCalendar::IslamicTabular);
+    /// assert_eq!(f.convert_chrono(from, to), "1 year 1 month ago");
+    /// f.civil_calendar(CivilCalendar::Gregorian);
+    /// assert_eq!(f.convert_chrono(from, to), "1 year 2 weeks ago");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn civil_calendar(&mut self, x: CivilCalendar) -> &mut Self {
+        self.civil_calendar = Some(x);
+        self
+    }
+
     /// Set maximum used unit. Not to be confused with `max_duration`.
     /// Should not affect appearance of "old" or other `too_high` values.
     /// ```
@@ -381,6 +972,35 @@ impl<L: Language> Formatter<L> {
         self
     }
 
+    /// Show the absolute date like "Mar 5" or "Mar 5, 2019" instead of relative output
+    /// for timespans longer than `x`, in `convert_chrono` and `convert_system_time`.
+    /// The year is shown only if it differs from the current one.
+    ///
+    /// This takes priority over `max_duration` and also applies to moments in the future.
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// let mut f = timeago::Formatter::new();
+    /// f.absolute_after(Duration::from_secs(7 * 86400));
+    /// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_710_410_400); // 14 March 2024
+    /// let day = Duration::from_secs(86400);
+    /// assert_eq!(f.convert_system_time(now - 3 * day, now), "3 days ago");
+    /// assert_eq!(f.convert_system_time(now - 9 * day, now), "Mar 5");
+    /// assert_eq!(f.convert_system_time(now - 375 * day, now), "Mar 5, 2023");
+    /// ```
+    pub fn absolute_after(&mut self, x: Duration) -> &mut Self {
+        self.absolute_after = Some(x);
+        self
+    }
+
+    /// Set the sections used by `timeline_bucket`. Moments of the disabled ones go to the next
+    /// enabled section after them, `TimelineBucket::Older` is always enabled. All are enabled by default.
+    #[cfg(feature = "chrono")]
+    pub fn timeline_buckets(&mut self, x: &[TimelineBucket]) -> &mut Self {
+        self.timeline_buckets = x.to_vec();
+        self.timeline_buckets.sort();
+        self
+    }
+
     /// Override what is used instead of "ago".
     /// Empty string literal `""` is a bit special in the space handling.
     /// ```
@@ -397,6 +1017,254 @@ impl<L: Language> Formatter<L> {
         self
     }
 
+    /// Phrase the output as the given relation instead of "ago", using the words of the language.
+    /// Idioms and `ago` only apply to `Relation::Ago`, which is the default.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::Relation;
+    /// let mut f = timeago::Formatter::new();
+    /// let d = Duration::from_secs(4 * 86400);
+    /// f.relation(Relation::Overdue);
+    /// assert_eq!(f.convert(d), "overdue by 4 days");
+    /// f.relation(Relation::DueIn);
+    /// assert_eq!(f.convert(d), "due in 4 days");
+    /// f.relation(Relation::In);
+    /// assert_eq!(f.convert(d), "in 4 days");
+    /// f.relation(Relation::Remaining);
+    /// assert_eq!(f.convert(d), "4 days remaining");
+    /// f.relation(Relation::Before);
+    /// assert_eq!(f.convert(d), "4 days before");
+    /// ```
+    pub fn relation(&mut self, x: Relation) -> &mut Self {
+        self.relation = x;
+        self
+    }
+
+    /// Set what `Relation::Before` and `Relation::After` refer to, like "release" in "3 days before release".
+    /// It is inserted as is, so it should already be in the form the language requires there.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::Relation;
+    /// let mut f = timeago::Formatter::new();
+    /// f.relation(Relation::After).reference("deploy");
+    /// assert_eq!(f.convert(Duration::from_secs(7200)), "2 hours after deploy");
+    /// ```
+    pub fn reference(&mut self, x: &'static str) -> &mut Self {
+        self.reference = Some(x);
+        self
+    }
+
+    /// Qualify the single item by the remainder instead of dropping it: "about" for less
+    /// than a quarter of the unit, "over" for less than three quarters and "almost" with
+    /// the next number otherwise. Durations shorter than `min_unit` give "less than a minute"
+    /// or similar. Only used when `num_items` is 1. Default is off.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::TimeUnit;
+    /// let mut f = timeago::Formatter::new();
+    /// f.qualifiers(true).min_unit(TimeUnit::Minutes);
+    /// assert_eq!(f.convert(Duration::from_secs(3600)), "1 hour ago");
+    /// assert_eq!(f.convert(Duration::from_secs(3600 + 600)), "about 1 hour ago");
+    /// assert_eq!(f.convert(Duration::from_secs(3600 + 1800)), "over 1 hour ago");
+    /// assert_eq!(f.convert(Duration::from_secs(3600 + 3000)), "almost 2 hours ago");
+    /// assert_eq!(f.convert(Duration::from_secs(30)), "less than a minute ago");
+    /// ```
+    pub fn qualifiers(&mut self, x: bool) -> &mut Self {
+        self.qualifiers = x;
+        self
+    }
+
+    /// Put ranges of `convert_range` in words, like "between 2 and 3 days ago"
+    /// instead of "2–3 days ago". Default is off.
+    pub fn range_words(&mut self, x: bool) -> &mut Self {
+        self.range_words = x;
+        self
+    }
+
+    /// Use idiomatic phrases provided by the language instead of the digit "1",
+    /// like "an hour ago" instead of "1 hour ago", and dedicated phrases like "yesterday"
+    /// or "vorgestern" instead of the whole "1 day ago" or "vor 2 Tagen".
+    ///
+    /// Dedicated whole phrases are only used when the output consists of a single item
+    /// and `ago` is not overridden. Default is off.
+    /// ```
+    /// use std::time::Duration;
+    /// let mut f = timeago::Formatter::new();
+    /// f.idioms(true);
+    /// assert_eq!(f.convert(Duration::from_secs(60)), "a minute ago");
+    /// assert_eq!(f.convert(Duration::from_secs(3600)), "an hour ago");
+    /// assert_eq!(f.convert(Duration::from_secs(86400)), "yesterday");
+    /// assert_eq!(f.convert(Duration::from_secs(2 * 86400)), "2 days ago");
+    /// f.num_items(2);
+    /// assert_eq!(f.convert(Duration::from_secs(3600 + 120)), "an hour 2 minutes ago");
+    /// ```
+    pub fn idioms(&mut self, x: bool) -> &mut Self {
+        self.idioms = x;
+        self
+    }
+
+    /// Put the final item as a fraction phrase provided by the language when the duration
+    /// is close to it, like "an hour and a half ago" for 1 hour 29 minutes. If the output
+    /// consists of a single item, halves and quarters of the bigger unit are also used,
+    /// like "half an hour ago" or "half a year ago". Default is off.
+    /// ```
+    /// use std::time::Duration;
+    /// let mut f = timeago::Formatter::new();
+    /// f.fractions(true);
+    /// assert_eq!(f.convert(Duration::from_secs(90 * 60)), "an hour and a half ago");
+    /// assert_eq!(f.convert(Duration::from_secs(150 * 60)), "2 and a half hours ago");
+    /// assert_eq!(f.convert(Duration::from_secs(29 * 60)), "half an hour ago");
+    /// assert_eq!(f.convert(Duration::from_secs(15 * 60)), "a quarter of an hour ago");
+    /// assert_eq!(f.convert(Duration::from_secs(20 * 60)), "20 minutes ago");
+    /// assert_eq!(f.convert(Duration::from_secs(183 * 86400)), "half a year ago");
+    /// f.num_items(2);
+    /// assert_eq!(f.convert(Duration::from_secs(86400 + 90 * 60)), "1 day an hour and a half ago");
+    /// ```
+    pub fn fractions(&mut self, x: bool) -> &mut Self {
+        self.fractions = x;
+        self
+    }
+
+    /// Enable a user-defined time unit, so that it participates in choosing
+    /// the dominant unit and in splitting the duration into multiple items.
+    /// Words for it are taken from the language, which normally falls back to [`CustomUnit::word`].
+    ///
+    /// Note that `max_unit` still applies, so it should be raised for units longer than a year.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::{CustomUnit, TimeUnit};
+    /// const DECADE: CustomUnit = CustomUnit::new("decade", "decades", Duration::from_secs(10 * 12 * 2_628_003));
+    /// let mut f = timeago::Formatter::new();
+    /// f.custom_unit(DECADE).max_unit(TimeUnit::Custom(DECADE)).num_items(2);
+    /// assert_eq!(f.convert(Duration::from_secs(12 * 12 * 2_628_003)), "1 decade 2 years ago");
+    /// ```
+    ///
+    /// [`CustomUnit::word`]:struct.CustomUnit.html#method.word
+    pub fn custom_unit(&mut self, u: CustomUnit) -> &mut Self {
+        if !self.custom_units.iter().any(|(x, _)| *x == u) {
+            self.custom_units.push((u, None));
+        }
+        self
+    }
+
+    /// Enable a user-defined time unit like `custom_unit`, also specifying words for it
+    /// in this formatter's language. The function gets the number of units.
+    /// ```
+    /// # #[cfg(feature = "translations")] {
+    /// use std::time::Duration;
+    /// use timeago::CustomUnit;
+    /// const QUARTER: CustomUnit = CustomUnit::new("quarter", "quarters", Duration::from_secs(3 * 2_628_003));
+    /// let mut f = timeago::Formatter::with_language(timeago::languages::russian::Russian);
+    /// f.custom_unit_words(QUARTER, |x| match x % 10 {
+    ///     1 if x % 100 != 11 => "квартал",
+    ///     2..=4 if !(12..=14).contains(&(x % 100)) => "квартала",
+    ///     _ => "кварталов",
+    /// });
+    /// assert_eq!(f.convert(Duration::from_secs(7 * 2_628_003)), "2 квартала назад");
+    /// # }
+    /// ```
+    pub fn custom_unit_words(
+        &mut self,
+        u: CustomUnit,
+        words: fn(u64) -> &'static str,
+    ) -> &mut Self {
+        self.custom_units.retain(|(x, _)| *x != u);
+        self.custom_units.push((u, Some(words)));
+        self
+    }
+
+    /// Set the duration starting from which the given unit is chosen as the dominant one,
+    /// instead of the default `min_duration` of that unit.
+    ///
+    /// Lowering the threshold makes the output "upgrade" early: a duration shorter
+    /// than one unit, but above the threshold is shown as one such unit.
+    /// Raising it keeps showing smaller units for longer, like "47 hours ago".
+    /// Apart from that the amounts are truncated as usual.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::TimeUnit;
+    /// let mut f = timeago::Formatter::new();
+    /// f.threshold(TimeUnit::Minutes, Duration::from_secs(45))
+    ///  .threshold(TimeUnit::Days, Duration::from_secs(22 * 3600))
+    ///  .threshold(TimeUnit::Months, Duration::from_secs(26 * 86400));
+    /// assert_eq!(f.convert(Duration::from_secs(44)), "44 seconds ago");
+    /// assert_eq!(f.convert(Duration::from_secs(50)), "1 minute ago");
+    /// assert_eq!(f.convert(Duration::from_secs(23 * 3600)), "1 day ago");
+    /// assert_eq!(f.convert(Duration::from_secs(27 * 86400)), "1 month ago");
+    ///
+    /// let mut f = timeago::Formatter::new();
+    /// f.threshold(TimeUnit::Days, Duration::from_secs(48 * 3600));
+    /// assert_eq!(f.convert(Duration::from_secs(25 * 3600)), "25 hours ago");
+    /// assert_eq!(f.convert(Duration::from_secs(48 * 3600)), "2 days ago");
+    /// ```
+    pub fn threshold(&mut self, tu: TimeUnit, x: Duration) -> &mut Self {
+        self.thresholds.retain(|(u, _)| *u != tu);
+        self.thresholds.push((tu, x));
+        self
+    }
+
+    fn threshold_of(&self, tu: TimeUnit) -> Duration {
+        self.thresholds
+            .iter()
+            .find(|(u, _)| *u == tu)
+            .map(|(_, x)| *x)
+            .unwrap_or_else(|| self.calendar.unit_length(tu))
+    }
+
+    /// Like [`TimeUnit::bigger_unit`], but also considering custom units enabled in this formatter.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::{CustomUnit, TimeUnit};
+    /// const QUARTER: CustomUnit = CustomUnit::new("quarter", "quarters", Duration::from_secs(3 * 2_628_003));
+    /// let mut f = timeago::Formatter::new();
+    /// f.custom_unit(QUARTER);
+    /// assert_eq!(f.bigger_unit(TimeUnit::Months), Some(TimeUnit::Custom(QUARTER)));
+    /// assert_eq!(f.bigger_unit(TimeUnit::Custom(QUARTER)), Some(TimeUnit::Years));
+    /// assert_eq!(f.smaller_unit(TimeUnit::Years), Some(TimeUnit::Custom(QUARTER)));
+    /// ```
+    ///
+    /// [`TimeUnit::bigger_unit`]:enum.TimeUnit.html#method.bigger_unit
+    pub fn bigger_unit(&self, tu: TimeUnit) -> Option<TimeUnit> {
+        self.units().find(|x| *x > tu)
+    }
+
+    /// Like [`TimeUnit::smaller_unit`], but also considering custom units enabled in this formatter.
+    ///
+    /// [`TimeUnit::smaller_unit`]:enum.TimeUnit.html#method.smaller_unit
+    pub fn smaller_unit(&self, tu: TimeUnit) -> Option<TimeUnit> {
+        self.units().filter(|x| *x < tu).last()
+    }
+
+    /// All the units this formatter can use, from the shortest to the longest
+    fn units(&self) -> impl Iterator<Item = TimeUnit> {
+        let mut v: Vec<TimeUnit> = BUILTIN_UNITS.to_vec();
+        v.extend(self.custom_units.iter().map(|(x, _)| TimeUnit::Custom(*x)));
+        v.sort_by_key(|x| (self.calendar.unit_length(*x), *x));
+        v.into_iter()
+    }
+
+    fn dominant_unit(&self, d: Duration) -> TimeUnit {
+        if self.custom_units.is_empty()
+            && self.thresholds.is_empty()
+            && self.calendar == CalendarModel::DEFAULT
+        {
+            return dominant_time_unit(d);
+        }
+        self.units()
+            .filter(|x| self.threshold_of(*x) <= d)
+            .last()
+            .unwrap_or(TimeUnit::Nanoseconds)
+    }
+
+    fn get_word(&self, tu: TimeUnit, x: u64, context: WordContext) -> &'static str {
+        if let TimeUnit::Custom(c) = tu {
+            if let Some((_, Some(words))) = self.custom_units.iter().find(|(u, _)| *u == c) {
+                return words(x);
+            }
+        }
+        self.lang.get_word_in(tu, x, context)
+    }
+
     /// Format the timespan between `from` and `to` as a string like "15 days ago".
     ///
     /// Requires `chrono` Cargo feature.
@@ -425,14 +1293,47 @@ impl<L: Language> Formatter<L> {
         Tz1: chrono::TimeZone,
         Tz2: chrono::TimeZone,
     {
-        let q = to.signed_duration_since(from);
-        if let Ok(dur) = q.to_std() {
-            self.convert(dur)
-        } else {
-            "???".to_owned()
+        match self.try_convert_chrono(from, to) {
+            Ok(x) => x,
+            Err(ConvertError::Negative) => "???".to_owned(),
+            Err(e) => self.convert_error(e),
         }
     }
 
+    /// Like `convert_chrono`, but returning errors instead of `"???"`, "now" or "old".
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// let f = timeago::Formatter::new();
+    /// let from = chrono::DateTime::parse_from_rfc3339("2013-12-19T15:00:00+03:00").unwrap();
+    /// let to   = chrono::DateTime::parse_from_rfc3339("2013-12-23T17:00:00+03:00").unwrap();
+    /// assert_eq!(f.try_convert_chrono(from, to), Ok("4 days ago".to_owned()));
+    /// assert_eq!(f.try_convert_chrono(to, from), Err(timeago::ConvertError::Negative));
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn try_convert_chrono<Tz1, Tz2>(
+        &self,
+        from: chrono::DateTime<Tz1>,
+        to: chrono::DateTime<Tz2>,
+    ) -> Result<String, ConvertError>
+    where
+        Tz1: chrono::TimeZone,
+        Tz2: chrono::TimeZone,
+    {
+        if let Some(x) = self.absolute_chrono(&from, &to) {
+            return Ok(x);
+        }
+        if let Some(cal) = self.civil_calendar {
+            let from = from.with_timezone(&to.timezone()).naive_local();
+            return self.convert_civil(cal, from, to.naive_local());
+        }
+        let q = to.signed_duration_since(from);
+        let dur = q.to_std().map_err(|_| ConvertError::Negative)?;
+        self.try_convert(dur)
+    }
+
     /// Convert specified [`Duration`] to a String representing
     /// approximation of specified timespan as a string like
     /// "5 days ago", with specified by other methods settings.
@@ -445,28 +1346,85 @@ impl<L: Language> Formatter<L> {
     ///
     /// [`Duration`]:https://doc.rust-lang.org/std/time/struct.Duration.html
     pub fn convert(&self, d: Duration) -> String {
-        if d > self.max_duration {
-            return self
+        match self.try_convert(d) {
+            Ok(x) => x,
+            Err(e) => self.convert_error(e),
+        }
+    }
+
+    /// What `convert` gives instead of the failed conversion
+    fn convert_error(&self, e: ConvertError) -> String {
+        match e {
+            ConvertError::TooLow => {
+                let now = self.too_low.unwrap_or_else(|| self.lang.too_low());
+                if now != "0" {
+                    return now.to_owned();
+                }
+                self.with_ago(format!(
+                    "0{}{}",
+                    self.lang.between_value_and_word(),
+                    self.get_word(self.min_unit, 0, WordContext::AGO)
+                ))
+            }
+            _ => self
                 .too_high
                 .unwrap_or_else(|| self.lang.too_high())
-                .to_owned();
+                .to_owned(),
         }
+    }
 
-        let mut ret = self.convert_impl(d, self.num_items);
+    /// Like `convert`, but report durations which cannot be represented with the current settings
+    /// as errors instead of `too_low` and `too_high` strings like "now" and "old".
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::{ConvertError, TimeUnit};
+    /// let mut f = timeago::Formatter::new();
+    /// f.min_unit(TimeUnit::Minutes).max_duration(Duration::from_secs(86400));
+    /// assert_eq!(f.try_convert(Duration::from_secs(60)), Ok("1 minute ago".to_owned()));
+    /// assert_eq!(f.try_convert(Duration::from_secs(30)), Err(ConvertError::TooLow));
+    /// assert_eq!(f.try_convert(Duration::from_secs(90000)), Err(ConvertError::TooHigh));
+    ///
+    /// let mut f = timeago::Formatter::new();
+    /// f.min_unit(TimeUnit::Nanoseconds).max_unit(TimeUnit::Nanoseconds);
+    /// let d = Duration::from_secs(1000 * 365 * 86400);
+    /// assert_eq!(f.try_convert(d), Err(ConvertError::Overflow(TimeUnit::Nanoseconds)));
+    /// ```
+    pub fn try_convert(&self, d: Duration) -> Result<String, ConvertError> {
+        if self.qualifiers && self.num_items == 1 {
+            return self.try_convert_qualified(d);
+        }
+        if self.relation != Relation::Ago {
+            let context = WordContext {
+                relation: self.relation,
+                qualifier: None,
+            };
+            return self.try_convert_in(d, context);
+        }
+        if d > self.max_duration {
+            return Err(ConvertError::TooHigh);
+        }
 
-        if ret.is_empty() {
-            let now = self.too_low.unwrap_or_else(|| self.lang.too_low());
-            if now != "0" {
-                return now.to_owned();
-            } else {
-                ret = format!(
-                    "0{}{}",
-                    self.lang.between_value_and_word(),
-                    self.lang.get_word(self.min_unit, 0)
-                );
+        if self.idioms && self.ago.is_none() {
+            if let Some((tu, x, rem)) = self.chunk(d)? {
+                if let Some(idiom) = self.lang.idiom(tu, x) {
+                    if self
+                        .convert_rest(tu, rem, self.num_items - 1, WordContext::AGO)?
+                        .is_empty()
+                    {
+                        return Ok(idiom.to_owned());
+                    }
+                }
             }
         }
 
+        let ret = self.convert_impl(d, self.num_items, None, WordContext::AGO)?;
+        if ret.is_empty() {
+            return Err(ConvertError::TooLow);
+        }
+        Ok(self.with_ago(ret))
+    }
+
+    fn with_ago(&self, ret: String) -> String {
         let ago = self.ago.unwrap_or_else(|| self.lang.ago());
 
         if ago.is_empty() {
@@ -478,38 +1436,124 @@ impl<L: Language> Formatter<L> {
         }
     }
 
-    fn convert_impl(&self, d: Duration, items_left: usize) -> String {
-        if items_left == 0 {
-            return "".to_owned();
-        }
-
-        let mut dtu = dominant_time_unit(d);
+    /// Choose the unit for the next chunk and split `d` by it.
+    /// Returns `None` if there is nothing to emit.
+    fn chunk(&self, d: Duration) -> Result<Option<(TimeUnit, u64, Duration)>, ConvertError> {
+        let mut dtu = self.dominant_unit(d);
 
         while dtu > self.max_unit {
-            dtu = dtu.smaller_unit().unwrap();
+            match self.smaller_unit(dtu) {
+                Some(x) => dtu = x,
+                None => break,
+            }
         }
 
         while dtu < self.min_unit {
-            dtu = dtu.bigger_unit().unwrap();
+            match self.bigger_unit(dtu) {
+                Some(x) => dtu = x,
+                None => return Ok(None),
+            }
         }
 
-        let (x, rem) = split_up(d, dtu);
+        let (x, mut rem) = split_by(d, self.calendar.unit_length(dtu));
+        let mut x = u64::try_from(x).map_err(|_| ConvertError::Overflow(dtu))?;
+
+        if x == 0 && d >= self.threshold_of(dtu) {
+            // upgraded early because of a lowered threshold
+            x = 1;
+            rem = Duration::new(0, 0);
+        }
 
         if x == 0 {
-            return "".to_owned();
+            return Ok(None);
+        }
+
+        Ok(Some((dtu, x, rem)))
+    }
+
+    /// Format up to `items_left` items, the first one using `unit` if specified
+    fn convert_impl(
+        &self,
+        d: Duration,
+        items_left: usize,
+        unit: Option<TimeUnit>,
+        context: WordContext,
+    ) -> Result<String, ConvertError> {
+        if items_left == 0 {
+            return Ok("".to_owned());
+        }
+
+        let (dtu, x, rem) = match unit {
+            None => match self.chunk(d)? {
+                Some(c) => c,
+                None => return Ok("".to_owned()),
+            },
+            Some(u) => {
+                let (x, rem) = split_by(d, self.calendar.unit_length(u));
+                if x == 0 && self.chunking != Chunking::AdjacentWithZeros {
+                    return Ok("".to_owned());
+                }
+                let x = u64::try_from(x).map_err(|_| ConvertError::Overflow(u))?;
+                (u, x, rem)
+            }
+        };
+
+        if items_left == 1 && self.fractions && context == WordContext::AGO {
+            if let Some(x) = self.fraction_item(dtu, x, rem, self.num_items == 1) {
+                return Ok(x);
+            }
         }
 
-        let recurse_result = self.convert_impl(rem, items_left - 1);
+        let recurse_result = self.convert_rest(dtu, rem, items_left - 1, context)?;
 
-        let word = self.lang.get_word(dtu, x);
+        if x == 1 && self.idioms && items_left == self.num_items && context == WordContext::AGO {
+            if let Some(one) = self.lang.one_unit(dtu) {
+                let between_chunk = self.lang.between_chunks();
+                return Ok(if recurse_result.is_empty() {
+                    one.to_owned()
+                } else {
+                    format!("{one}{between_chunk}{recurse_result}")
+                });
+            }
+        }
+
+        Ok(self.join_item(dtu, x, recurse_result, context))
+    }
+
+    /// Format one item like "5 days" and prepend it to the following ones
+    fn join_item(&self, tu: TimeUnit, x: u64, rest: String, context: WordContext) -> String {
+        self.join_word(x, self.get_word(tu, x, context), rest)
+    }
+
+    /// Like `join_item`, but with the specified unit word
+    fn join_word(&self, x: u64, word: &str, rest: String) -> String {
         let between = self.lang.between_value_and_word();
         let between_chunk = self.lang.between_chunks();
 
-        match (self.lang.place_unit_before(x), recurse_result.is_empty()) {
+        match (self.lang.place_unit_before(x), rest.is_empty()) {
             (true, true) => format!("{word}{between}{x}"),
-            (true, false) => format!("{word}{between}{x}{between_chunk}{recurse_result}"),
+            (true, false) => format!("{word}{between}{x}{between_chunk}{rest}"),
             (false, true) => format!("{x}{between}{word}"),
-            (false, false) => format!("{x}{between}{word}{between_chunk}{recurse_result}"),
+            (false, false) => format!("{x}{between}{word}{between_chunk}{rest}"),
+        }
+    }
+
+    /// Format the items following the one that used unit `tu`
+    fn convert_rest(
+        &self,
+        tu: TimeUnit,
+        rem: Duration,
+        items_left: usize,
+        context: WordContext,
+    ) -> Result<String, ConvertError> {
+        match self.chunking {
+            Chunking::Free => self.convert_impl(rem, items_left, None, context),
+            Chunking::Adjacent | Chunking::AdjacentWithZeros => match self.smaller_unit(tu) {
+                Some(u) if u >= self.min_unit => {
+                    self.convert_impl(rem, items_left, Some(u), context)
+                }
+                _ => Ok("".to_owned()),
+            },
         }
     }
 }
@@ -531,55 +1575,28 @@ fn dominant_time_unit(d: Duration) -> TimeUnit {
     }
 }
 
-fn divmod64(a: u64, b: u64) -> (u64, u64) {
-    (a / b, a % b)
-}
-fn divmod32(a: u32, b: u32) -> (u32, u32) {
-    (a / b, a % b)
+/// Split `d` into the whole number of units `tu` and the remainder
+fn split_up(d: Duration, tu: TimeUnit) -> (u128, Duration) {
+    split_by(d, tu.min_duration())
 }
 
-fn split_up(d: Duration, tu: TimeUnit) -> (u64, Duration) {
-    let s = d.as_secs();
-    let n = d.subsec_nanos();
-
-    let tud = tu.min_duration();
-    let tus = tud.as_secs();
-    let tun = tud.subsec_nanos();
-
-    if tus != 0 {
-        assert!(tun == 0);
-        if s == 0 {
-            (0, d)
-        } else {
-            let (c, s2) = divmod64(s, tus);
-            (c, Duration::new(s2, n))
-        }
-    } else {
-        // subsecond timeunit
-        assert!(tus == 0);
-        if s == 0 {
-            let (c, n2) = divmod32(n, tun);
-            (c.into(), Duration::new(0, n2))
-        } else {
-            assert!(1_000_000_000_u32 % tun == 0);
-            let tuninv = 1_000_000_000 / (u64::from(tun));
-            let pieces = s.saturating_mul(tuninv).saturating_add(u64::from(n / tun));
-
-            let subtract_s = pieces / tuninv;
-            let subtract_ns = ((pieces % tuninv) as u32) * tun;
-
-            let (mut s, mut n) = (s, n);
-
-            if subtract_ns > n {
-                s -= 1;
-                n += 1_000_000_000;
-            }
+/// Split `d` into the whole number of pieces of length `unit` and the remainder
+fn split_by(d: Duration, unit: Duration) -> (u128, Duration) {
+    let unit = unit.as_nanos();
+    let nanos = d.as_nanos();
+    let rem = nanos % unit;
+    // remainder is shorter than the unit, so it fits
+    let rem = Duration::new((rem / 1_000_000_000) as u64, (rem % 1_000_000_000) as u32);
+    (nanos / unit, rem)
+}
 
-            let remain_s = s - subtract_s;
-            let remain_ns = n - subtract_ns;
-            (pieces, Duration::new(remain_s, remain_ns))
-        }
-    }
+/// Shortest duration that is at least `num / den` of `len`
+fn part_of(len: Duration, num: u128, den: u128) -> Duration {
+    let nanos = (len.as_nanos() * num + den - 1) / den;
+    Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    )
 }
 
 #[cfg(test)]
@@ -602,6 +1619,24 @@ mod tests_split_up {
         assert_eq!(dominant_time_unit(dn(0, 250_000_000)), Milliseconds);
     }
 
+    #[test]
+    fn time_unit_order() {
+        use TimeUnit::*;
+
+        for w in BUILTIN_UNITS.windows(2) {
+            assert!(w[0] < w[1], "{:?} {:?}", w[0], w[1]);
+        }
+        let quarter = Custom(CustomUnit::new("quarter", "quarters", ds(3 * S_IN_MNTH)));
+        assert!(quarter > Months && quarter < Years);
+        // a custom unit of the same length comes after the built-in one
+        let day = Custom(CustomUnit::new("day", "days", ds(86400)));
+        assert!(day > Days && day < Weeks);
+        // custom units of the same length are ordered by their words
+        let cycle = Custom(CustomUnit::new("cycle", "cycles", ds(86400)));
+        assert!(cycle < day);
+        assert_eq!(day.cmp(&day), std::cmp::Ordering::Equal);
+    }
+
     #[test]
     fn split_up_test_sane() {
         use TimeUnit::*;
@@ -635,7 +1670,7 @@ mod tests_split_up {
         );
         assert_eq!(
             split_up(ds(3600_000_000_000), Nanoseconds),
-            (std::u64::MAX, dn(3581_553_255_926, 290448385))
+            (3_600_000_000_000_000_000_000, ds(0))
         );
         assert_eq!(
             split_up(ds(3600_000_000_000), Microseconds),
@@ -643,7 +1678,7 @@ mod tests_split_up {
         );
         assert_eq!(
             split_up(ds(3600_000_000_000_000), Microseconds),
-            (std::u64::MAX, dn(3581_553_255_926_290, 448385000))
+            (3_600_000_000_000_000_000_000, ds(0))
         );
         assert_eq!(
             split_up(ds(3600_000_000_000_000), Milliseconds),
@@ -651,7 +1686,15 @@ mod tests_split_up {
         );
         assert_eq!(
             split_up(ds(3600_000_000_000_000_000), Milliseconds),
-            (std::u64::MAX, dn(3581_553_255_926_290_448, 385000000))
+            (3_600_000_000_000_000_000_000, ds(0))
+        );
+        assert_eq!(
+            split_up(dn(3600_000_000_000_000, 1_500), Microseconds),
+            (3_600_000_000_000_000_000_001, dn(0, 500))
+        );
+        assert_eq!(
+            split_up(dn(u64::MAX, 999_999_999), Nanoseconds),
+            (18_446_744_073_709_551_615_999_999_999, ds(0))
         );
     }
 }
@@ -659,17 +1702,29 @@ mod tests_split_up {
 /// A simplified formatter, resulting in short strings like "02Yea" or " now " or "07min".
 /// Designed to always give 5-character strings.
 pub fn format_5chars(d: Duration) -> String {
+    format_5chars_with(d, CalendarModel::DEFAULT)
+}
+
+/// Like `format_5chars`, but with the specified lengths of months and years.
+/// ```
+/// use std::time::Duration;
+/// use timeago::{format_5chars, format_5chars_with, CalendarModel};
+/// let d = Duration::from_secs(360 * 86400);
+/// assert_eq!(format_5chars(d), "11Mon");
+/// assert_eq!(format_5chars_with(d, CalendarModel::THIRTY_360), "01Yea");
+/// ```
+pub fn format_5chars_with(d: Duration, model: CalendarModel) -> String {
     let s = d.as_secs();
+    let month = model.month_length().as_secs();
+    let year = model.year_length().as_secs();
     match s {
         0 => " now ".into(),
         x if (1..60).contains(&x) => format!("{x:02}sec"),
         x if (60..3600).contains(&x) => format!("{:02}min", x / 60),
         x if (3600..86400).contains(&x) => format!("{:02}hou", x / 3600),
-        x if (86400..S_IN_MNTH).contains(&x) => format!("{:02}day", x / 86400),
-        x if (S_IN_MNTH..(12 * S_IN_MNTH)).contains(&x) => format!("{:02}Mon", x / S_IN_MNTH),
-        x if ((12 * S_IN_MNTH)..=(99 * 12 * S_IN_MNTH)).contains(&x) => {
-            format!("{:02}Yea", x / (12 * S_IN_MNTH))
-        }
+        x if (86400..month).contains(&x) => format!("{:02}day", x / 86400),
+        x if (month..year).contains(&x) => format!("{:02}Mon", x / month),
+        x if (year..=(99 * year)).contains(&x) => format!("{:02}Yea", x / year),
         _ => " OLD ".into(),
     }
 }
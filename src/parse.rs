@@ -0,0 +1,147 @@
+//! Error type and helpers shared by parsers of machine-readable duration formats.
+
+use std::fmt;
+
+/// What went wrong when parsing a duration string
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// Input is empty or ended prematurely
+    UnexpectedEnd,
+    /// A character that is not allowed at this position
+    UnexpectedChar(char),
+    /// A number is followed by a unit not supported in this format
+    UnknownUnit(String),
+    /// A unit appears twice or out of order
+    UnitOrder,
+    /// Only the last component may have a fractional part
+    MisplacedFraction,
+    /// The value does not fit into `std::time::Duration`
+    Overflow,
+    /// Negative durations are not representable as `std::time::Duration`
+    Negative,
+}
+
+/// Error from parsing a duration string, with byte position of the problem
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    position: usize,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub(crate) fn new(position: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { position, kind }
+    }
+
+    /// Byte offset in the input where the problem was found
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// What was wrong
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseErrorKind::*;
+        match self.kind {
+            UnexpectedEnd => write!(f, "unexpected end of input")?,
+            UnexpectedChar(c) => write!(f, "unexpected character {c:?}")?,
+            UnknownUnit(ref u) => write!(f, "unknown unit {u:?}")?,
+            UnitOrder => write!(f, "unit is repeated or out of order")?,
+            MisplacedFraction => write!(f, "only the last component may be fractional")?,
+            Overflow => write!(f, "duration is too long")?,
+            Negative => write!(f, "negative durations are not supported")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A decimal number like "12" or "1.25" found in the input
+pub(crate) struct Number {
+    pub int: u64,
+    /// Digits after the decimal separator
+    pub frac: Option<String>,
+}
+
+impl Number {
+    /// Total nanoseconds in this number of units of given length
+    pub fn nanos(&self, unit_nanos: u128) -> Option<u128> {
+        let mut ret = u128::from(self.int).checked_mul(unit_nanos)?;
+        if let Some(ref frac) = self.frac {
+            let mut scale = unit_nanos;
+            for c in frac.bytes() {
+                if scale < 10 {
+                    // further digits are below nanosecond precision
+                    break;
+                }
+                scale /= 10;
+                ret = ret.checked_add(u128::from(c - b'0') * scale)?;
+            }
+        }
+        Some(ret)
+    }
+}
+
+/// Parse a number starting at `i`, allowing any of `separators` between integer and fractional parts.
+/// Returns the number and the position after it, or `None` if there are no digits at `i`.
+pub(crate) fn number(
+    s: &str,
+    i: usize,
+    separators: &[u8],
+) -> Result<Option<(Number, usize)>, ParseError> {
+    let b = s.as_bytes();
+    let mut j = i;
+    while j < b.len() && b[j].is_ascii_digit() {
+        j += 1;
+    }
+    if j == i {
+        return Ok(None);
+    }
+    let int = s[i..j]
+        .parse()
+        .map_err(|_| ParseError::new(i, ParseErrorKind::Overflow))?;
+    if j < b.len() && separators.contains(&b[j]) {
+        let k = j + 1;
+        let mut e = k;
+        while e < b.len() && b[e].is_ascii_digit() {
+            e += 1;
+        }
+        if e == k {
+            return Err(unexpected(s, e));
+        }
+        return Ok(Some((
+            Number {
+                int,
+                frac: Some(s[k..e].to_owned()),
+            },
+            e,
+        )));
+    }
+    Ok(Some((Number { int, frac: None }, j)))
+}
+
+/// Error for whatever is at position `i`: either the end of input or some character
+pub(crate) fn unexpected(s: &str, i: usize) -> ParseError {
+    match s[i..].chars().next() {
+        None => ParseError::new(i, ParseErrorKind::UnexpectedEnd),
+        Some(c) => ParseError::new(i, ParseErrorKind::UnexpectedChar(c)),
+    }
+}
+
+/// Convert total nanoseconds to `Duration`, failing if it is too long
+pub(crate) fn nanos_to_duration(
+    x: u128,
+    position: usize,
+) -> Result<std::time::Duration, ParseError> {
+    let secs = u64::try_from(x / 1_000_000_000)
+        .map_err(|_| ParseError::new(position, ParseErrorKind::Overflow))?;
+    Ok(std::time::Duration::new(secs, (x % 1_000_000_000) as u32))
+}
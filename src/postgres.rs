@@ -0,0 +1,715 @@
+//! PostgreSQL `interval` text representation, like "1 year 2 mons 3 days 04:05:06.5".
+//!
+//! PostgreSQL keeps months, days and time of an interval separately, so does [`Interval`].
+//! Conversion from and to `std::time::Duration` uses the same approximate month length as
+//! the rest of the crate (about 30.4 days), while days are always 24 hours.
+//!
+//! ```
+//! use timeago::postgres::{Interval, IntervalStyle};
+//! let i = Interval::parse("1 year 2 mons 3 days 04:05:06.5").unwrap();
+//! assert_eq!(i, Interval { months: 14, days: 3, microseconds: 14_706_500_000 });
+//! assert_eq!(i.format(IntervalStyle::Iso8601), "P1Y2M3DT4H5M6.5S");
+//! assert_eq!(i.format(IntervalStyle::SqlStandard), "+1-2 +3 +4:05:06.5");
+//! ```
+//!
+//! [`Interval`]:struct.Interval.html
+
+use super::parse::{number, unexpected, Number, ParseError, ParseErrorKind};
+use super::{TimeUnit, S_IN_MNTH};
+use std::time::Duration;
+
+const US_IN_HOUR: i64 = 3_600_000_000;
+const US_IN_MINUTE: i64 = 60_000_000;
+const US_IN_SECOND: i64 = 1_000_000;
+const US_IN_DAY: i64 = 24 * US_IN_HOUR;
+
+/// Output format of an interval, corresponding to PostgreSQL's `IntervalStyle` setting
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum IntervalStyle {
+    /// `postgres`, the default: "1 year 2 mons 3 days 04:05:06"
+    Postgres,
+    /// `postgres_verbose`: "@ 1 year 2 mons 3 days 4 hours 5 mins 6 secs"
+    PostgresVerbose,
+    /// `sql_standard`: "1-2", "3 4:05:06" or "+1-2 +3 +4:05:06"
+    SqlStandard,
+    /// `iso_8601`: "P1Y2M3DT4H5M6S"
+    Iso8601,
+}
+
+/// PostgreSQL interval with separate months, days and time fields, each of which may be negative
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Interval {
+    /// Months, including twelve for each year
+    pub months: i32,
+    /// Days, not combined with months or time
+    pub days: i32,
+    /// Time part
+    pub microseconds: i64,
+}
+
+/// Fields as PostgreSQL shows them: year, month, day, hour, minute, second, microsecond
+struct Fields {
+    year: i64,
+    mon: i64,
+    day: i64,
+    hour: i64,
+    min: i64,
+    sec: i64,
+    usec: i64,
+}
+
+/// Seconds with optional fraction, without trailing zeros
+fn seconds(sec: i64, usec: i64, pad: bool) -> String {
+    let mut ret = if pad {
+        format!("{:02}", sec.abs())
+    } else {
+        sec.abs().to_string()
+    };
+    if usec != 0 {
+        let frac = format!("{:06}", usec.abs());
+        ret.push('.');
+        ret += frac.trim_end_matches('0');
+    }
+    ret
+}
+
+fn plural(x: i64) -> &'static str {
+    if x == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+impl Interval {
+    /// Split the duration into months, days and time, not using units bigger than `max_unit`.
+    /// Returns `None` if it does not fit into the fields.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::postgres::Interval;
+    /// use timeago::TimeUnit;
+    /// let d = Duration::from_secs(40 * 86400 + 3600);
+    /// let i = Interval::from_duration(d, TimeUnit::Years).unwrap();
+    /// assert_eq!((i.months, i.days), (1, 9));
+    /// let i = Interval::from_duration(d, TimeUnit::Days).unwrap();
+    /// assert_eq!((i.months, i.days, i.microseconds), (0, 40, 3_600_000_000));
+    /// ```
+    pub fn from_duration(d: Duration, max_unit: TimeUnit) -> Option<Interval> {
+        let mut secs = d.as_secs();
+        let mut ret = Interval::default();
+        if max_unit >= TimeUnit::Months {
+            ret.months = i32::try_from(secs / S_IN_MNTH).ok()?;
+            secs %= S_IN_MNTH;
+        }
+        if max_unit >= TimeUnit::Days {
+            ret.days = i32::try_from(secs / 86400).ok()?;
+            secs %= 86400;
+        }
+        ret.microseconds = i64::try_from(secs)
+            .ok()?
+            .checked_mul(US_IN_SECOND)?
+            .checked_add(i64::from(d.subsec_micros()))?;
+        Some(ret)
+    }
+
+    /// Total length, or `None` if it is negative
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::postgres::Interval;
+    /// let i = Interval { months: 0, days: 1, microseconds: -3_600_000_000 };
+    /// assert_eq!(i.to_duration(), Some(Duration::from_secs(23 * 3600)));
+    /// ```
+    pub fn to_duration(&self) -> Option<Duration> {
+        let us = i128::from(self.months) * i128::from(S_IN_MNTH) * 1_000_000
+            + i128::from(self.days) * i128::from(US_IN_DAY)
+            + i128::from(self.microseconds);
+        if us < 0 {
+            return None;
+        }
+        let secs = u64::try_from(us / 1_000_000).ok()?;
+        Some(Duration::new(secs, (us % 1_000_000) as u32 * 1000))
+    }
+
+    fn fields(&self) -> Fields {
+        let t = self.microseconds;
+        Fields {
+            year: i64::from(self.months / 12),
+            mon: i64::from(self.months % 12),
+            day: i64::from(self.days),
+            hour: t / US_IN_HOUR,
+            min: t % US_IN_HOUR / US_IN_MINUTE,
+            sec: t % US_IN_MINUTE / US_IN_SECOND,
+            usec: t % US_IN_SECOND,
+        }
+    }
+
+    /// Format the interval like PostgreSQL does with the given `IntervalStyle`.
+    /// ```
+    /// use timeago::postgres::{Interval, IntervalStyle::*};
+    /// let i = Interval { months: 14, days: 3, microseconds: 14_706_500_000 };
+    /// assert_eq!(i.format(Postgres), "1 year 2 mons 3 days 04:05:06.5");
+    /// assert_eq!(i.format(PostgresVerbose), "@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs");
+    /// let i = Interval { months: 0, days: -1, microseconds: -7_200_000_000 };
+    /// assert_eq!(i.format(Postgres), "-1 days -02:00:00");
+    /// assert_eq!(i.format(PostgresVerbose), "@ 1 day 2 hours ago");
+    /// assert_eq!(i.format(SqlStandard), "-1 2:00:00");
+    /// assert_eq!(i.format(Iso8601), "P-1DT-2H");
+    /// ```
+    pub fn format(&self, style: IntervalStyle) -> String {
+        match style {
+            IntervalStyle::Postgres => self.format_postgres(),
+            IntervalStyle::PostgresVerbose => self.format_verbose(),
+            IntervalStyle::SqlStandard => self.format_sql_standard(),
+            IntervalStyle::Iso8601 => self.format_iso8601(),
+        }
+    }
+
+    fn format_postgres(&self) -> String {
+        let f = self.fields();
+        let mut ret = String::new();
+        let mut is_before = false;
+        for (value, unit) in [(f.year, "year"), (f.mon, "mon"), (f.day, "day")] {
+            if value == 0 {
+                continue;
+            }
+            if !ret.is_empty() {
+                ret.push(' ');
+            }
+            let sign = if is_before && value > 0 { "+" } else { "" };
+            ret += &format!("{sign}{value} {unit}{}", plural(value));
+            is_before = value < 0;
+        }
+        if ret.is_empty() || self.microseconds != 0 {
+            let minus = self.microseconds < 0;
+            let sign = match (minus, is_before) {
+                (true, _) => "-",
+                (false, true) => "+",
+                (false, false) => "",
+            };
+            let space = if ret.is_empty() { "" } else { " " };
+            ret += &format!(
+                "{space}{sign}{:02}:{:02}:{}",
+                f.hour.abs(),
+                f.min.abs(),
+                seconds(f.sec, f.usec, true)
+            );
+        }
+        ret
+    }
+
+    fn format_verbose(&self) -> String {
+        let f = self.fields();
+        let mut ret = "@".to_owned();
+        let mut is_zero = true;
+        let mut is_before = false;
+        let parts = [
+            (f.year, "year"),
+            (f.mon, "mon"),
+            (f.day, "day"),
+            (f.hour, "hour"),
+            (f.min, "min"),
+        ];
+        for (mut value, unit) in parts {
+            if value == 0 {
+                continue;
+            }
+            if is_zero {
+                is_before = value < 0;
+                value = value.abs();
+            } else if is_before {
+                value = -value;
+            }
+            ret += &format!(" {value} {unit}{}", plural(value));
+            is_zero = false;
+        }
+        if f.sec != 0 || f.usec != 0 {
+            let negative = f.sec < 0 || f.usec < 0;
+            if is_zero {
+                is_before = negative;
+            }
+            let sign = if !is_zero && negative != is_before {
+                "-"
+            } else {
+                ""
+            };
+            let s = if f.sec.abs() == 1 && f.usec == 0 {
+                ""
+            } else {
+                "s"
+            };
+            ret += &format!(" {sign}{} sec{s}", seconds(f.sec, f.usec, false));
+            is_zero = false;
+        }
+        if is_zero {
+            ret += " 0";
+        }
+        if is_before {
+            ret += " ago";
+        }
+        ret
+    }
+
+    fn format_sql_standard(&self) -> String {
+        let mut f = self.fields();
+        let values = [f.year, f.mon, f.day, f.hour, f.min, f.sec, f.usec];
+        let has_negative = values.iter().any(|x| *x < 0);
+        let has_positive = values.iter().any(|x| *x > 0);
+        let has_year_month = f.year != 0 || f.mon != 0;
+        let has_day_time = values[2..].iter().any(|x| *x != 0);
+        let mixed = (has_negative && has_positive) || (has_year_month && has_day_time);
+
+        if !has_negative && !has_positive {
+            return "0".to_owned();
+        }
+        if mixed {
+            let sign = |negative: bool| if negative { '-' } else { '+' };
+            let time_negative = f.hour < 0 || f.min < 0 || f.sec < 0 || f.usec < 0;
+            return format!(
+                "{}{}-{} {}{} {}{}:{:02}:{}",
+                sign(f.year < 0 || f.mon < 0),
+                f.year.abs(),
+                f.mon.abs(),
+                sign(f.day < 0),
+                f.day.abs(),
+                sign(time_negative),
+                f.hour.abs(),
+                f.min.abs(),
+                seconds(f.sec, f.usec, true),
+            );
+        }
+        let mut ret = String::new();
+        if has_negative {
+            ret.push('-');
+            f = Fields {
+                year: -f.year,
+                mon: -f.mon,
+                day: -f.day,
+                hour: -f.hour,
+                min: -f.min,
+                sec: -f.sec,
+                usec: -f.usec,
+            };
+        }
+        let time = || format!("{}:{:02}:{}", f.hour, f.min, seconds(f.sec, f.usec, true));
+        if has_year_month {
+            ret += &format!("{}-{}", f.year, f.mon);
+        } else if f.day != 0 {
+            ret += &format!("{} {}", f.day, time());
+        } else {
+            ret += &time();
+        }
+        ret
+    }
+
+    fn format_iso8601(&self) -> String {
+        let f = self.fields();
+        if self.months == 0 && self.days == 0 && self.microseconds == 0 {
+            return "PT0S".to_owned();
+        }
+        let mut ret = "P".to_owned();
+        for (value, letter) in [(f.year, 'Y'), (f.mon, 'M'), (f.day, 'D')] {
+            if value != 0 {
+                ret += &format!("{value}{letter}");
+            }
+        }
+        if self.microseconds != 0 {
+            ret.push('T');
+            for (value, letter) in [(f.hour, 'H'), (f.min, 'M')] {
+                if value != 0 {
+                    ret += &format!("{value}{letter}");
+                }
+            }
+            if f.sec != 0 || f.usec != 0 {
+                let sign = if f.sec < 0 || f.usec < 0 { "-" } else { "" };
+                ret += &format!("{sign}{}S", seconds(f.sec, f.usec, false));
+            }
+        }
+        ret
+    }
+
+    /// Parse interval text in any of the styles PostgreSQL outputs, see [`IntervalStyle`].
+    ///
+    /// Fractional years, months and days are spread to smaller fields like PostgreSQL does,
+    /// assuming 30 days per month. In the SQL standard format a leading minus applies
+    /// to all the fields.
+    /// ```
+    /// use timeago::postgres::Interval;
+    /// let i = Interval { months: 14, days: 3, microseconds: 14_706_500_000 };
+    /// assert_eq!(Interval::parse("1 year 2 mons 3 days 04:05:06.5"), Ok(i));
+    /// assert_eq!(Interval::parse("@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs"), Ok(i));
+    /// assert_eq!(Interval::parse("+1-2 +3 +4:05:06.5"), Ok(i));
+    /// assert_eq!(Interval::parse("P1Y2M3DT4H5M6.5S"), Ok(i));
+    /// let i = Interval { months: 0, days: -1, microseconds: -7_200_000_000 };
+    /// assert_eq!(Interval::parse("-1 2:00:00"), Ok(i));
+    /// assert_eq!(Interval::parse("@ 1 day 2 hours ago"), Ok(i));
+    /// assert_eq!(Interval::parse("1.5 years").unwrap().months, 18);
+    /// assert_eq!(Interval::parse("3 fortnights").unwrap_err().position(), 2);
+    /// ```
+    ///
+    /// [`IntervalStyle`]:enum.IntervalStyle.html
+    pub fn parse(s: &str) -> Result<Interval, ParseError> {
+        let trimmed = s.trim_start();
+        let offset = s.len() - trimmed.len();
+        if trimmed.starts_with('P') {
+            return parse_iso8601(s, offset);
+        }
+        let mut acc = Accumulator::default();
+        let mut i = offset;
+        if trimmed.starts_with('@') {
+            i += 1;
+        }
+        let tokens = tokenize(s, i);
+        if tokens.is_empty() {
+            return Err(unexpected(s, s.len()));
+        }
+
+        let has_words = tokens
+            .iter()
+            .any(|(_, t)| t.starts_with(|c: char| c.is_alphabetic()));
+        let explicit_signs = tokens
+            .iter()
+            .filter(|(_, t)| t.starts_with(['+', '-']))
+            .count();
+        // SQL standard: leading minus applies to all the fields
+        let global_minus =
+            !has_words && explicit_signs == 1 && tokens[0].1.starts_with('-') && tokens.len() > 1;
+
+        let mut k = 0;
+        while k < tokens.len() {
+            let (pos, tok) = tokens[k];
+            if tok == "ago" && k == tokens.len() - 1 && k > 0 {
+                acc.negate();
+                break;
+            }
+            let (negative, body, body_pos) = split_sign(tok, pos);
+            let negative = negative || (global_minus && k > 0);
+            if body.contains(':') {
+                acc.add_time(s, body, body_pos, negative)?;
+            } else if body.contains('-') {
+                acc.add_year_month(s, body, body_pos, negative)?;
+            } else {
+                let (n, j) = match number(s, body_pos, b".")? {
+                    Some(x) => x,
+                    None => return Err(unexpected(s, body_pos)),
+                };
+                // unit may be attached like "5days" or be the next token
+                let (unit, unit_pos) = if j < pos + tok.len() {
+                    (&s[j..pos + tok.len()], j)
+                } else if let Some((p, t)) = tokens
+                    .get(k + 1)
+                    .filter(|(_, t)| t.starts_with(|c: char| c.is_alphabetic()) && *t != "ago")
+                {
+                    k += 1;
+                    (*t, *p)
+                } else {
+                    // bare number is days, like in "3 4:05:06"
+                    ("day", pos)
+                };
+                acc.add(&n, negative, unit, unit_pos)?;
+            }
+            k += 1;
+        }
+        acc.finish(0)
+    }
+}
+
+/// Whitespace-separated tokens with their positions
+fn tokenize(s: &str, from: usize) -> Vec<(usize, &str)> {
+    let mut ret = Vec::new();
+    let mut start = None;
+    for (i, c) in s[from..].char_indices() {
+        let i = i + from;
+        match (c.is_whitespace(), start) {
+            (true, Some(st)) => {
+                ret.push((st, &s[st..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => (),
+        }
+    }
+    if let Some(st) = start {
+        ret.push((st, &s[st..]));
+    }
+    ret
+}
+
+fn split_sign(tok: &str, pos: usize) -> (bool, &str, usize) {
+    match tok.as_bytes().first() {
+        Some(b'-') => (true, &tok[1..], pos + 1),
+        Some(b'+') => (false, &tok[1..], pos + 1),
+        _ => (false, tok, pos),
+    }
+}
+
+/// Interval fields being parsed, kept wider to detect overflow at the end
+#[derive(Default)]
+struct Accumulator {
+    months: i128,
+    days: i128,
+    microseconds: i128,
+}
+
+impl Accumulator {
+    fn negate(&mut self) {
+        self.months = -self.months;
+        self.days = -self.days;
+        self.microseconds = -self.microseconds;
+    }
+
+    /// Add `n` units named `unit`, spreading fractions to smaller fields
+    fn add(
+        &mut self,
+        n: &Number,
+        negative: bool,
+        unit: &str,
+        pos: usize,
+    ) -> Result<(), ParseError> {
+        let sign = if negative { -1 } else { 1 };
+        let overflow = || ParseError::new(pos, ParseErrorKind::Overflow);
+        // amounts in millionths of the unit, to handle fractions
+        let micro = |unit: u128| n.nanos(unit * 1_000_000).ok_or_else(overflow);
+        let us = |unit: i64| {
+            n.nanos(unit as u128)
+                .map(|x| sign * x as i128)
+                .ok_or_else(overflow)
+        };
+        match unit.to_ascii_lowercase().as_str() {
+            "year" | "years" | "yr" | "yrs" | "y" => {
+                self.months += sign * (micro(12)? / 1_000_000) as i128;
+            }
+            "mon" | "mons" | "month" | "months" => {
+                let m = micro(1)?;
+                self.months += sign * (m / 1_000_000) as i128;
+                let days = (m % 1_000_000) as i128 * 30;
+                self.days += sign * days / 1_000_000;
+                self.microseconds += sign * (days % 1_000_000) * i128::from(US_IN_DAY) / 1_000_000;
+            }
+            "week" | "weeks" | "w" => {
+                let d = micro(7)?;
+                self.days += sign * (d / 1_000_000) as i128;
+                self.microseconds +=
+                    sign * (d % 1_000_000) as i128 * i128::from(US_IN_DAY) / 1_000_000;
+            }
+            "day" | "days" | "d" => {
+                let d = micro(1)?;
+                self.days += sign * (d / 1_000_000) as i128;
+                self.microseconds +=
+                    sign * (d % 1_000_000) as i128 * i128::from(US_IN_DAY) / 1_000_000;
+            }
+            "hour" | "hours" | "hr" | "hrs" | "h" => self.microseconds += us(US_IN_HOUR)?,
+            "min" | "mins" | "minute" | "minutes" | "m" => self.microseconds += us(US_IN_MINUTE)?,
+            "sec" | "secs" | "second" | "seconds" | "s" => self.microseconds += us(US_IN_SECOND)?,
+            "millisecond" | "milliseconds" | "ms" => self.microseconds += us(1000)?,
+            "microsecond" | "microseconds" | "us" => self.microseconds += us(1)?,
+            _ => {
+                return Err(ParseError::new(
+                    pos,
+                    ParseErrorKind::UnknownUnit(unit.to_owned()),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Add "H:MM", "H:MM:SS" or "H:MM:SS.ffffff"
+    fn add_time(
+        &mut self,
+        s: &str,
+        body: &str,
+        pos: usize,
+        negative: bool,
+    ) -> Result<(), ParseError> {
+        let end = pos + body.len();
+        let mut parts = Vec::new();
+        let mut i = pos;
+        loop {
+            let (n, j) = match number(s, i, b".")? {
+                Some(x) if x.1 <= end => x,
+                _ => return Err(unexpected(s, i)),
+            };
+            parts.push((n, i));
+            if j == end {
+                break;
+            }
+            if s.as_bytes()[j] != b':' || parts.len() == 3 {
+                return Err(unexpected(s, j));
+            }
+            i = j + 1;
+        }
+        if parts.len() < 2
+            || parts[..parts.len() - 1]
+                .iter()
+                .any(|(n, _)| n.frac.is_some())
+        {
+            return Err(ParseError::new(pos, ParseErrorKind::MisplacedFraction));
+        }
+        let units = [US_IN_HOUR, US_IN_MINUTE, US_IN_SECOND];
+        for ((n, p), unit) in parts.iter().zip(units) {
+            let x = n
+                .nanos(unit as u128)
+                .ok_or_else(|| ParseError::new(*p, ParseErrorKind::Overflow))?;
+            self.microseconds += if negative { -(x as i128) } else { x as i128 };
+        }
+        Ok(())
+    }
+
+    /// Add "Y-M"
+    fn add_year_month(
+        &mut self,
+        s: &str,
+        body: &str,
+        pos: usize,
+        negative: bool,
+    ) -> Result<(), ParseError> {
+        let end = pos + body.len();
+        let (y, j) = match number(s, pos, b"")? {
+            Some(x) if s.as_bytes().get(x.1) == Some(&b'-') => x,
+            _ => return Err(unexpected(s, pos)),
+        };
+        let (m, k) = match number(s, j + 1, b"")? {
+            Some(x) => x,
+            None => return Err(unexpected(s, j + 1)),
+        };
+        if k != end {
+            return Err(unexpected(s, k));
+        }
+        let months = i128::from(y.int) * 12 + i128::from(m.int);
+        self.months += if negative { -months } else { months };
+        Ok(())
+    }
+
+    fn finish(self, pos: usize) -> Result<Interval, ParseError> {
+        let overflow = || ParseError::new(pos, ParseErrorKind::Overflow);
+        Ok(Interval {
+            months: i32::try_from(self.months).map_err(|_| overflow())?,
+            days: i32::try_from(self.days).map_err(|_| overflow())?,
+            microseconds: i64::try_from(self.microseconds).map_err(|_| overflow())?,
+        })
+    }
+}
+
+/// Parse the `iso_8601` style, where each component may have its own sign
+fn parse_iso8601(s: &str, offset: usize) -> Result<Interval, ParseError> {
+    let b = s.as_bytes();
+    let end = s.trim_end().len();
+    let mut acc = Accumulator::default();
+    let mut i = offset + 1;
+    let mut in_time = false;
+    let mut any = false;
+    while i < end {
+        if b[i] == b'T' && !in_time {
+            in_time = true;
+            i += 1;
+            continue;
+        }
+        let (negative, start) = match b[i] {
+            b'-' => (true, i + 1),
+            b'+' => (false, i + 1),
+            _ => (false, i),
+        };
+        let (n, j) = match number(s, start, b".,")? {
+            Some(x) => x,
+            None => return Err(unexpected(s, start)),
+        };
+        let unit = match (b.get(j), in_time) {
+            (Some(b'Y'), false) => "year",
+            (Some(b'M'), false) => "mon",
+            (Some(b'W'), false) => "week",
+            (Some(b'D'), false) => "day",
+            (Some(b'H'), true) => "hour",
+            (Some(b'M'), true) => "min",
+            (Some(b'S'), true) => "sec",
+            (Some(_), _) => {
+                let u = s[j..].chars().next().unwrap().to_string();
+                return Err(ParseError::new(j, ParseErrorKind::UnknownUnit(u)));
+            }
+            (None, _) => return Err(unexpected(s, j)),
+        };
+        acc.add(&n, negative, unit, j)?;
+        any = true;
+        i = j + 1;
+    }
+    if !any {
+        return Err(unexpected(s, i));
+    }
+    acc.finish(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let samples = [
+            Interval::default(),
+            Interval {
+                months: 14,
+                days: 3,
+                microseconds: 14_706_500_000,
+            },
+            Interval {
+                months: -14,
+                days: -3,
+                microseconds: -14_706_500_000,
+            },
+            Interval {
+                months: 1,
+                days: -3,
+                microseconds: 1,
+            },
+            Interval {
+                months: 0,
+                days: 0,
+                microseconds: -61_000_000,
+            },
+            Interval {
+                months: 12,
+                days: 0,
+                microseconds: 0,
+            },
+            Interval {
+                months: 0,
+                days: 5,
+                microseconds: 0,
+            },
+        ];
+        for i in samples {
+            for style in [
+                IntervalStyle::Postgres,
+                IntervalStyle::PostgresVerbose,
+                IntervalStyle::SqlStandard,
+                IntervalStyle::Iso8601,
+            ] {
+                let text = i.format(style);
+                assert_eq!(Interval::parse(&text), Ok(i), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn formats() {
+        use IntervalStyle::*;
+        let zero = Interval::default();
+        assert_eq!(zero.format(Postgres), "00:00:00");
+        assert_eq!(zero.format(PostgresVerbose), "@ 0");
+        assert_eq!(zero.format(SqlStandard), "0");
+        assert_eq!(zero.format(Iso8601), "PT0S");
+        let i = Interval {
+            months: 14,
+            days: 0,
+            microseconds: 0,
+        };
+        assert_eq!(i.format(Postgres), "1 year 2 mons");
+        assert_eq!(i.format(SqlStandard), "1-2");
+        let i = Interval {
+            months: 1,
+            days: -3,
+            microseconds: 3_600_000_000,
+        };
+        assert_eq!(i.format(Postgres), "1 mon -3 days +01:00:00");
+        assert_eq!(i.format(PostgresVerbose), "@ 1 mon -3 days 1 hour");
+        assert_eq!(i.format(SqlStandard), "+0-1 -3 +1:00:00");
+    }
+}
@@ -0,0 +1,126 @@
+//! Output with the buckets of `distance_of_time_in_words` from Ruby on Rails.
+
+use super::{Formatter, Language, Qualifier, TimeUnit};
+use std::time::Duration;
+
+const MINUTES_IN_YEAR: u64 = 525_600;
+const MINUTES_IN_QUARTER_YEAR: u64 = 131_400;
+const MINUTES_IN_THREE_QUARTERS_YEAR: u64 = 394_200;
+
+impl<L: Language> Formatter<L> {
+    /// Format the duration exactly like `distance_of_time_in_words` of Ruby on Rails
+    /// without `include_seconds` does: "less than a minute", "44 minutes", "about 2 hours", "1 day",
+    /// "about 1 month", "11 months", "over 3 years" and so on, rounding to the nearest minute first.
+    ///
+    /// Only the language and the relation are taken from the formatter,
+    /// so set `ago` to `""` to get the Rails output as is.
+    /// Unlike Rails, leap days are not subtracted from long durations, see `distance_of_time_in_words_chrono`.
+    /// ```
+    /// use std::time::Duration;
+    /// let mut f = timeago::Formatter::new();
+    /// f.ago("");
+    /// let m = |x: u64| f.distance_of_time_in_words(Duration::from_secs(x * 60));
+    /// assert_eq!(f.distance_of_time_in_words(Duration::from_secs(29)), "less than a minute");
+    /// assert_eq!(f.distance_of_time_in_words(Duration::from_secs(30)), "1 minute");
+    /// assert_eq!(m(44), "44 minutes");
+    /// assert_eq!(m(45), "about 1 hour");
+    /// assert_eq!(m(90), "about 2 hours");
+    /// assert_eq!(m(1439), "about 24 hours");
+    /// assert_eq!(m(1440), "1 day");
+    /// assert_eq!(m(2520), "2 days");
+    /// assert_eq!(m(43199), "30 days");
+    /// assert_eq!(m(43200), "about 1 month");
+    /// assert_eq!(m(64799), "about 1 month");
+    /// assert_eq!(m(64800), "about 2 months");
+    /// assert_eq!(m(86400), "2 months");
+    /// assert_eq!(m(525599), "12 months");
+    /// assert_eq!(m(525600), "about 1 year");
+    /// assert_eq!(m(525600 + 131400), "over 1 year");
+    /// assert_eq!(m(525600 + 394200), "almost 2 years");
+    ///
+    /// let f = timeago::Formatter::new();
+    /// assert_eq!(f.distance_of_time_in_words(Duration::from_secs(3 * 3600)), "about 3 hours ago");
+    /// ```
+    pub fn distance_of_time_in_words(&self, d: Duration) -> String {
+        self.rails(d, 0)
+    }
+
+    /// Format the timespan between `from` and `to` like `distance_of_time_in_words`,
+    /// also subtracting the leap days in between from spans longer than a year as Rails does.
+    /// The order of `from` and `to` does not matter.
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::{TimeZone, Utc};
+    /// let mut f = timeago::Formatter::new();
+    /// f.ago("");
+    /// let from = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    /// let to = Utc.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(f.distance_of_time_in_words_chrono(from, to), "over 2 years");
+    /// assert_eq!(f.distance_of_time_in_words_chrono(to, from), "over 2 years");
+    /// // without subtracting 29 February 2024
+    /// let d = (to - from).to_std().unwrap();
+    /// assert_eq!(f.distance_of_time_in_words(d), "almost 3 years");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn distance_of_time_in_words_chrono<Tz1, Tz2>(
+        &self,
+        from: chrono::DateTime<Tz1>,
+        to: chrono::DateTime<Tz2>,
+    ) -> String
+    where
+        Tz1: chrono::TimeZone,
+        Tz2: chrono::TimeZone,
+    {
+        use chrono::{Datelike, NaiveDate};
+        let (from, to) = (from.with_timezone(&to.timezone()), to);
+        let d = to.clone().signed_duration_since(from.clone());
+        let (from, to) = if d < chrono::Duration::zero() {
+            (to.naive_local(), from.naive_local())
+        } else {
+            (from.naive_local(), to.naive_local())
+        };
+        let d = d.abs().to_std().unwrap_or_default();
+
+        let from_year = from.year() + i32::from(from.month() >= 3);
+        let to_year = to.year() - i32::from(to.month() < 3);
+        let leap_years = (from_year..=to_year)
+            .filter(|x| NaiveDate::from_ymd_opt(*x, 2, 29).is_some())
+            .count();
+        self.rails(d, leap_years as u64)
+    }
+
+    fn rails(&self, d: Duration, leap_years: u64) -> String {
+        use Qualifier::*;
+        use TimeUnit::*;
+        let minutes = (d.as_secs_f64() / 60.0).round() as u64;
+        let rounded = |unit: u64| (minutes as f64 / unit as f64).round() as u64;
+        let item = |tu, x| {
+            let context = self.context(None);
+            self.relate(self.join_item(tu, x, String::new(), context), context)
+        };
+        match minutes {
+            0 => self.qualified_item(Minutes, 1, LessThan),
+            1..=44 => item(Minutes, minutes),
+            45..=89 => self.qualified_item(Hours, 1, About),
+            90..=1439 => self.qualified_item(Hours, rounded(60), About),
+            1440..=2519 => item(Days, 1),
+            2520..=43199 => item(Days, rounded(1440)),
+            43200..=86399 => self.qualified_item(Months, rounded(43200), About),
+            86400..=525599 => item(Months, rounded(43200)),
+            _ => {
+                let minutes = minutes.saturating_sub(leap_years * 1440);
+                let years = minutes / MINUTES_IN_YEAR;
+                match minutes % MINUTES_IN_YEAR {
+                    x if x < MINUTES_IN_QUARTER_YEAR => self.qualified_item(Years, years, About),
+                    x if x < MINUTES_IN_THREE_QUARTERS_YEAR => {
+                        self.qualified_item(Years, years, Over)
+                    }
+                    _ => self.qualified_item(Years, years + 1, Almost),
+                }
+            }
+        }
+    }
+}
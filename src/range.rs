@@ -0,0 +1,126 @@
+//! Ranges of durations like "2–3 hours ago" for uncertain moments.
+
+use super::{Formatter, Language, Qualifier, Relation};
+use std::time::Duration;
+
+impl<L: Language> Formatter<L> {
+    /// Format the range between two durations, sharing the unit if both bounds use it,
+    /// like "2–3 hours ago", or with separate units otherwise, like "45 minutes – 2 hours ago".
+    /// The bounds can be in any order, and each of them uses a single item.
+    ///
+    /// Lower bounds shorter than `min_unit` count as zero of the upper bound's unit,
+    /// upper bounds give `too_low` and `too_high` like `convert` does.
+    /// ```
+    /// use std::time::Duration;
+    /// let h = |x: u64| Duration::from_secs(x * 3600);
+    /// let mut f = timeago::Formatter::new();
+    /// assert_eq!(f.convert_range(h(2), h(3)), "2–3 hours ago");
+    /// assert_eq!(f.convert_range(h(3), h(2)), "2–3 hours ago");
+    /// assert_eq!(f.convert_range(h(1), h(2)), "1–2 hours ago");
+    /// assert_eq!(f.convert_range(Duration::from_secs(45 * 60), h(2)), "45 minutes – 2 hours ago");
+    /// assert_eq!(f.convert_range(Duration::from_secs(10), Duration::from_secs(20)), "10–20 seconds ago");
+    /// assert_eq!(f.convert_range(h(2), h(2)), "2 hours ago");
+    /// f.range_words(true);
+    /// assert_eq!(f.convert_range(h(48), h(72)), "between 2 and 3 days ago");
+    /// f.relation(timeago::Relation::Before).reference("release");
+    /// assert_eq!(f.convert_range(h(48), h(72)), "between 2 and 3 days before release");
+    /// ```
+    pub fn convert_range(&self, lo: Duration, hi: Duration) -> String {
+        let (lo, hi) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+        if hi > self.max_duration {
+            return self.convert(hi);
+        }
+        let (tu2, x2) = match self.chunk(hi) {
+            Ok(Some((tu, x, _))) => (tu, x),
+            _ => return self.convert(hi),
+        };
+        let (tu1, x1) = match self.chunk(lo) {
+            Ok(Some((tu, x, _))) => (tu, x),
+            Ok(None) => (tu2, 0),
+            Err(_) => return self.convert(hi),
+        };
+
+        let context = self.context(if self.range_words {
+            Some(Qualifier::Between)
+        } else {
+            None
+        });
+        let item = |tu, x| self.join_item(tu, x, String::new(), context);
+        let lower = || {
+            if tu1 == tu2 {
+                x1.to_string()
+            } else {
+                item(tu1, x1)
+            }
+        };
+        let ago = self.ago.unwrap_or_else(|| self.lang.ago());
+        let distinct = (tu1, x1) != (tu2, x2);
+        if self.range_words && distinct && self.relation == Relation::Ago && !ago.is_empty() {
+            // the language places the ago word itself, like "vor 2 bis 3 Tagen"
+            return self.lang.range_between(&lower(), &item(tu2, x2), Some(ago));
+        }
+        let ret = if !distinct {
+            item(tu2, x2)
+        } else if self.range_words {
+            self.lang.range_between(&lower(), &item(tu2, x2), None)
+        } else if tu1 == tu2 {
+            format!("{x1}{}{}", self.lang.range_separator(true), item(tu2, x2))
+        } else {
+            let separator = self.lang.range_separator(false);
+            format!("{}{separator}{}", item(tu1, x1), item(tu2, x2))
+        };
+        self.relate(ret, self.context(None))
+    }
+}
+
+#[cfg(feature = "translations")]
+#[test]
+fn test_languages() {
+    use super::languages::{
+        chinese::Chinese, german::German, japanese::Japanese, korean::Korean, russian::Russian,
+        turkish::Turkish,
+    };
+    let h = |x: u64| Duration::from_secs(x * 3600);
+    let mut f = Formatter::with_language(Russian);
+    assert_eq!(f.convert_range(h(1), h(2)), "1–2 часа назад");
+    assert_eq!(f.convert_range(h(2), h(5)), "2–5 часов назад");
+    assert_eq!(f.convert_range(h(2), h(21)), "2–21 час назад");
+    assert_eq!(
+        f.convert_range(Duration::from_secs(45 * 60), h(2)),
+        "45 минут – 2 часа назад"
+    );
+    f.range_words(true);
+    assert_eq!(f.convert_range(h(48), h(72)), "от 2 до 3 дней назад");
+    assert_eq!(
+        f.convert_range(h(24), h(21 * 24)),
+        "от 1 дня до 3 недель назад"
+    );
+    let mut f = Formatter::with_language(German);
+    assert_eq!(f.convert_range(h(48), h(72)), "vor 2–3 Tagen");
+    f.range_words(true);
+    assert_eq!(f.convert_range(h(48), h(120)), "vor 2 bis 5 Tagen");
+    f.ago("");
+    assert_eq!(f.convert_range(h(48), h(72)), "zwischen 2 und 3 Tagen");
+    f.relation(Relation::Before);
+    assert_eq!(f.convert_range(h(48), h(72)), "zwischen 2 und 3 Tagen vor");
+    f.reference("dem Release");
+    assert_eq!(
+        f.convert_range(h(48), h(72)),
+        "zwischen 2 und 3 Tagen vor dem Release"
+    );
+    f.range_words(false);
+    assert_eq!(f.convert_range(h(48), h(72)), "2–3 Tage vor dem Release");
+    let mut f = Formatter::with_language(Japanese);
+    assert_eq!(f.convert_range(h(2), h(3)), "2〜3時間前");
+    f.range_words(true);
+    assert_eq!(f.convert_range(h(48), h(120)), "2から5日前");
+    let mut f = Formatter::with_language(Korean);
+    f.range_words(true);
+    assert_eq!(f.convert_range(h(48), h(120)), "2에서 5일 전");
+    let mut f = Formatter::with_language(Chinese);
+    f.range_words(true);
+    assert_eq!(f.convert_range(h(48), h(120)), "2 到 5 天 之前");
+    let mut f = Formatter::with_language(Turkish);
+    f.range_words(true);
+    assert_eq!(f.convert_range(h(48), h(120)), "2 ila 5 gün önce");
+}
@@ -0,0 +1,163 @@
+//! Phrases relating a timespan to a moment, like "3 minutes ago" or "about 3 minutes remaining".
+
+use super::{ConvertError, Formatter, Language, TimeUnit};
+use std::time::Duration;
+
+/// How the timespan relates to the moment it is measured from
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Relation {
+    /// The timespan has passed, like "3 minutes ago". This is what `convert` gives.
+    Ago,
+    /// The timespan is left until something finishes, like "3 minutes remaining"
+    Remaining,
+    /// The moment comes the timespan before the reference, like "3 days before release"
+    Before,
+    /// The moment comes the timespan after the reference, like "2 hours after deploy"
+    After,
+    /// The deadline has passed the timespan ago, like "overdue by 4 days"
+    Overdue,
+    /// The deadline comes in the timespan, like "due in 2 days"
+    DueIn,
+    /// The moment comes in the timespan, like "in 2 days"
+    In,
+}
+
+/// Word marking the amount as approximate
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Qualifier {
+    /// Like "about 3 minutes"
+    About,
+    /// Like "over 3 minutes"
+    Over,
+    /// Like "almost 3 minutes"
+    Almost,
+    /// Like "less than a minute"
+    LessThan,
+    /// Like "between 2 and 3 days" in ranges. The phrase itself comes from
+    /// `Language::range_between`, so `qualify` leaves such items as they are.
+    Between,
+}
+
+/// Grammatical context the unit words are used in, see `Language::get_word_in`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct WordContext {
+    /// The phrase the items are put into
+    pub relation: Relation,
+    /// The word the items follow, if any
+    pub qualifier: Option<Qualifier>,
+}
+
+impl WordContext {
+    /// Context of the items in `convert`
+    pub const AGO: WordContext = WordContext {
+        relation: Relation::Ago,
+        qualifier: None,
+    };
+}
+
+impl<L: Language> Formatter<L> {
+    /// Like `try_convert`, but putting the items into the given context instead of "ago"
+    pub(crate) fn try_convert_in(
+        &self,
+        d: Duration,
+        context: WordContext,
+    ) -> Result<String, ConvertError> {
+        if d > self.max_duration {
+            return Err(ConvertError::TooHigh);
+        }
+        let ret = self.convert_impl(d, self.num_items, None, context)?;
+        if ret.is_empty() {
+            return Err(ConvertError::TooLow);
+        }
+        Ok(self.relate(ret, context))
+    }
+
+    /// Like `try_convert` in the qualifier mode: a single item qualified
+    /// by how much of the next unit the remainder makes up
+    pub(crate) fn try_convert_qualified(&self, d: Duration) -> Result<String, ConvertError> {
+        if d > self.max_duration {
+            return Err(ConvertError::TooHigh);
+        }
+        let (tu, x, q) = match self.chunk(d)? {
+            None => (self.min_unit, 1, Qualifier::LessThan),
+            Some((tu, x, rem)) if rem.is_zero() => {
+                let context = self.context(None);
+                let item = self.join_item(tu, x, String::new(), context);
+                return Ok(self.relate(item, context));
+            }
+            Some((tu, x, rem)) => {
+                let len = self.calendar.unit_length(tu);
+                match rem.as_nanos() * 4 / len.as_nanos() {
+                    0 => (tu, x, Qualifier::About),
+                    1 | 2 => (tu, x, Qualifier::Over),
+                    // "almost 1 hour" rather than "almost 60 minutes"
+                    _ => match self.chunk(d - rem + len)? {
+                        Some((up, y, r)) if r.is_zero() => (up, y, Qualifier::Almost),
+                        _ => (tu, x + 1, Qualifier::Almost),
+                    },
+                }
+            }
+        };
+        Ok(self.qualified_item(tu, x, q))
+    }
+
+    /// Single item like "about 3 hours ago", with the current relation
+    pub(crate) fn qualified_item(&self, tu: TimeUnit, x: u64, q: Qualifier) -> String {
+        let context = self.context(Some(q));
+        let item = match self.lang.one_unit_in(tu, context) {
+            Some(one) if x == 1 && q == Qualifier::LessThan => one.to_owned(),
+            _ => self.join_item(tu, x, String::new(), context),
+        };
+        self.relate(item, context)
+    }
+
+    /// Context of the items with the current relation
+    pub(crate) fn context(&self, qualifier: Option<Qualifier>) -> WordContext {
+        WordContext {
+            relation: self.relation,
+            qualifier,
+        }
+    }
+
+    /// Qualify the items and put them into the relation phrase
+    pub(crate) fn relate(&self, items: String, context: WordContext) -> String {
+        let ret = match context.qualifier {
+            Some(Qualifier::Between) | None => items,
+            Some(q) => self.lang.qualify(q, &items),
+        };
+        let space = self.lang.override_space_near_ago();
+        match context.relation {
+            Relation::Ago => self.with_ago(ret),
+            Relation::Remaining => {
+                let word = self.lang.remaining();
+                if self.lang.place_remaining_before() {
+                    format!("{word}{space}{ret}")
+                } else {
+                    format!("{ret}{space}{word}")
+                }
+            }
+            r => {
+                let (before, after) = self.lang.relation_words(r);
+                let mut ret = ret;
+                if !before.is_empty() {
+                    ret = format!("{before}{space}{ret}");
+                }
+                if !after.is_empty() {
+                    ret = format!("{ret}{space}{after}");
+                }
+                match self.reference {
+                    Some(x) if r == Relation::Before || r == Relation::After => {
+                        if self.lang.place_reference_before() {
+                            format!("{x}{space}{ret}")
+                        } else {
+                            format!("{ret}{space}{x}")
+                        }
+                    }
+                    _ => ret,
+                }
+            }
+        }
+    }
+}
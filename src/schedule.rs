@@ -0,0 +1,308 @@
+//! Moments when the output changes, so that live-updating views know when to render again.
+
+use super::absolute::utc_date;
+use super::civil::{CivilCalendar, UNIX_EPOCH_RD};
+use super::{part_of, split_by, Chunking, English, Formatter, Fraction, Language};
+use std::time::{Duration, SystemTime};
+
+/// Give up looking for a change after checking this many candidate points
+const MAX_STEPS: usize = 1000;
+
+/// Earliest offset at which `text_at` differs from `text`.
+/// `candidates` gives offsets after the given one, which must include every point where the text may change.
+fn first_change(
+    text: &str,
+    candidates: impl Fn(Duration) -> Vec<Duration>,
+    text_at: impl Fn(Duration) -> String,
+) -> Option<Duration> {
+    let mut offset = Duration::new(0, 0);
+    for _ in 0..MAX_STEPS {
+        offset = candidates(offset)
+            .into_iter()
+            .filter(|x| *x > offset)
+            .min()?;
+        if text_at(offset) != text {
+            return Some(offset);
+        }
+    }
+    None
+}
+
+impl<L: Language> Formatter<L> {
+    /// Format the duration like `convert` and also tell how much longer the duration
+    /// has to grow for the output to become different. `None` means it never changes again.
+    /// ```
+    /// use std::time::Duration;
+    /// use timeago::TimeUnit;
+    /// let mut f = timeago::Formatter::new();
+    /// let (text, next) = f.convert_with_next_change(Duration::from_secs(5 * 60 + 20));
+    /// assert_eq!(text, "5 minutes ago");
+    /// assert_eq!(next, Some(Duration::from_secs(40)));
+    ///
+    /// f.num_items(2);
+    /// let (text, next) = f.convert_with_next_change(Duration::from_millis(3600_500));
+    /// assert_eq!(text, "1 hour ago");
+    /// assert_eq!(next, Some(Duration::from_millis(500)));
+    ///
+    /// f.min_unit(TimeUnit::Minutes).max_duration(Duration::from_secs(7200));
+    /// let (text, next) = f.convert_with_next_change(Duration::from_secs(10));
+    /// assert_eq!(text, "now");
+    /// assert_eq!(next, Some(Duration::from_secs(50)));
+    /// let (text, next) = f.convert_with_next_change(Duration::from_secs(7170));
+    /// assert_eq!(text, "1 hour 59 minutes ago");
+    /// assert_eq!(next, Some(Duration::from_secs(30)));
+    /// let (text, next) = f.convert_with_next_change(Duration::from_secs(7200));
+    /// assert_eq!(text, "2 hours ago");
+    /// assert_eq!(next, Some(Duration::new(0, 1)));
+    /// assert_eq!(f.convert_with_next_change(Duration::from_secs(7201)), ("old".to_owned(), None));
+    /// ```
+    pub fn convert_with_next_change(&self, d: Duration) -> (String, Option<Duration>) {
+        let text = self.convert(d);
+        let next = first_change(
+            &text,
+            |offset| self.offsets_from(d, d + offset),
+            |offset| self.convert(d + offset),
+        );
+        (text, next)
+    }
+
+    /// Successive changes of the output as the duration grows from `d`:
+    /// durations at which the output changes together with the new output.
+    /// ```
+    /// use std::time::Duration;
+    /// let f = timeago::Formatter::new();
+    /// let mut changes = f.changes(Duration::from_secs(58));
+    /// assert_eq!(changes.next(), Some((Duration::from_secs(59), "59 seconds ago".to_owned())));
+    /// assert_eq!(changes.next(), Some((Duration::from_secs(60), "1 minute ago".to_owned())));
+    /// assert_eq!(changes.next(), Some((Duration::from_secs(120), "2 minutes ago".to_owned())));
+    /// ```
+    pub fn changes(&self, d: Duration) -> Changes<'_, L> {
+        Changes {
+            formatter: self,
+            d: Some(d),
+        }
+    }
+
+    /// Format the timespan from `t` to `now` like `convert_system_time` and also tell
+    /// how much time has to pass after `now` for the output to become different.
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// let mut f = timeago::Formatter::new();
+    /// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_710_410_400);
+    /// let t = now - Duration::from_secs(3 * 3600 + 600);
+    /// let (text, next) = f.convert_system_time_with_next_change(t, now);
+    /// assert_eq!(text, "3 hours ago");
+    /// assert_eq!(next, Some(Duration::from_secs(50 * 60)));
+    ///
+    /// let (text, next) = f.convert_system_time_with_next_change(now + Duration::from_secs(5), now);
+    /// assert_eq!(text, "???");
+    /// assert_eq!(next, Some(Duration::from_secs(5)));
+    ///
+    /// // absolute dates only change when a new year begins
+    /// f.absolute_after(Duration::from_secs(7 * 86400));
+    /// let t = now - Duration::from_secs(30 * 86400);
+    /// let (text, next) = f.convert_system_time_with_next_change(t, now);
+    /// assert_eq!(text, "Feb 13");
+    /// assert_eq!(next, Some(Duration::from_secs(292 * 86400 + 14 * 3600)));
+    /// ```
+    pub fn convert_system_time_with_next_change(
+        &self,
+        t: SystemTime,
+        now: SystemTime,
+    ) -> (String, Option<Duration>) {
+        let text = self.convert_system_time(t, now);
+        let next = first_change(
+            &text,
+            |offset| self.system_time_offsets(t, now, offset),
+            |offset| self.convert_system_time(t, now + offset),
+        );
+        (text, next)
+    }
+
+    /// Candidate offsets from `now` for `convert_system_time_with_next_change`
+    /// after `now` is moved forward by `offset`
+    fn system_time_offsets(
+        &self,
+        t: SystemTime,
+        now: SystemTime,
+        offset: Duration,
+    ) -> Vec<Duration> {
+        let current = now + offset;
+        let mut ret = Vec::new();
+        let mut push = |x: SystemTime| {
+            if let Ok(x) = x.duration_since(now) {
+                ret.push(x);
+            }
+        };
+        match current.duration_since(t) {
+            Ok(span) => {
+                for x in self.change_points(span) {
+                    push(t + x);
+                }
+                if let Some(x) = self.absolute_after {
+                    push(t + x + Duration::new(0, 1));
+                }
+            }
+            Err(_) => {
+                push(t);
+                if let Some(x) = self.absolute_after {
+                    if let Some(x) = t.checked_sub(x) {
+                        push(x);
+                    }
+                }
+            }
+        }
+        if self.absolute_after.is_some() {
+            let year = utc_date(current).0;
+            let rd = CivilCalendar::Gregorian.rata_die(year as i64 + 1, 1, 1);
+            let secs = (rd - UNIX_EPOCH_RD) * 86400;
+            if secs > 0 {
+                push(SystemTime::UNIX_EPOCH + Duration::from_secs(secs as u64));
+            }
+        }
+        ret
+    }
+
+    /// Offsets from `start` of the change points after `d`
+    fn offsets_from(&self, start: Duration, d: Duration) -> Vec<Duration> {
+        self.change_points(d)
+            .into_iter()
+            .map(|x| x - start)
+            .collect()
+    }
+
+    /// Durations after `d` where the output of `convert` may change.
+    /// Includes every actual change, but not every candidate is one.
+    fn change_points(&self, d: Duration) -> Vec<Duration> {
+        let mut ret = Vec::new();
+        let mut push = |x: Option<Duration>| match x {
+            Some(x) if x > d => ret.push(x),
+            _ => (),
+        };
+        if d > self.max_duration {
+            return ret;
+        }
+        push(self.max_duration.checked_add(Duration::new(0, 1)));
+
+        // Walk the items like `convert_impl` does. `base` is the part of `d` taken by the items so far.
+        let mut base = Duration::new(0, 0);
+        let mut rem = d;
+        let mut unit = None;
+        for i in 0..self.num_items {
+            // the dominant unit of the remainder changes at thresholds
+            for u in self.units() {
+                push(base.checked_add(self.threshold_of(u)));
+            }
+            let (tu, r) = match unit {
+                None => match self.chunk(rem) {
+                    Ok(Some((tu, _, r))) => (tu, r),
+                    _ => break,
+                },
+                Some(u) => (u, split_by(rem, self.calendar.unit_length(u)).1),
+            };
+            let len = self.calendar.unit_length(tu);
+            if rem < len {
+                // either a zero item or one upgraded early because of a lowered threshold,
+                // nothing after it is shown until the unit is reached
+                push(base.checked_add(len));
+                if self.chunking != Chunking::AdjacentWithZeros || unit.is_none() {
+                    break;
+                }
+            }
+            // the number of this item grows by one
+            let counted = base + (rem - r);
+            push(counted.checked_add(len));
+            if self.qualifiers && self.num_items == 1 {
+                // the qualifier appears with the remainder and changes at its quarters
+                push(counted.checked_add(Duration::new(0, 1)));
+                for k in 1..4 {
+                    push(counted.checked_add(part_of(len, k, 4)));
+                }
+            }
+            if self.fractions && i + 1 == self.num_items {
+                // the final item may be phrased as a fraction of its unit or of the bigger one
+                let mut windows = vec![(counted, Fraction::Half.window(len))];
+                match self.bigger_unit(tu).filter(|u| *u <= self.max_unit) {
+                    Some(u) if self.num_items == 1 => {
+                        let len = self.calendar.unit_length(u);
+                        windows.push((base, Fraction::Half.window(len)));
+                        windows.push((base, Fraction::Quarter.window(len)));
+                    }
+                    _ => (),
+                }
+                for (from, w) in windows {
+                    push(from.checked_add(w.start));
+                    push(from.checked_add(w.end));
+                }
+            }
+            base = counted;
+            rem = r;
+            unit = match self.chunking {
+                Chunking::Free => None,
+                _ => match self.smaller_unit(tu) {
+                    Some(u) if u >= self.min_unit => Some(u),
+                    _ => break,
+                },
+            };
+        }
+        ret
+    }
+}
+
+/// Iterator over successive changes of the output, see `Formatter::changes`
+pub struct Changes<'a, L: Language = English> {
+    formatter: &'a Formatter<L>,
+    d: Option<Duration>,
+}
+
+impl<'a, L: Language> Iterator for Changes<'a, L> {
+    type Item = (Duration, String);
+
+    fn next(&mut self) -> Option<(Duration, String)> {
+        let d = self.d?;
+        self.d = self
+            .formatter
+            .convert_with_next_change(d)
+            .1
+            .and_then(|x| d.checked_add(x));
+        let d = self.d?;
+        Some((d, self.formatter.convert(d)))
+    }
+}
+
+#[test]
+fn test_exhaustive() {
+    use super::TimeUnit;
+    let mut f = Formatter::new();
+    f.num_items(2).min_unit(TimeUnit::Seconds);
+    let mut g = Formatter::new();
+    g.num_items(3)
+        .chunking(Chunking::Adjacent)
+        .idioms(true)
+        .threshold(TimeUnit::Days, Duration::from_secs(20 * 3600));
+    let mut q = Formatter::new();
+    q.qualifiers(true);
+    let mut h = Formatter::new();
+    h.fractions(true);
+    let mut h2 = Formatter::new();
+    h2.fractions(true).num_items(2).min_unit(TimeUnit::Minutes);
+    let hour = Duration::from_secs(3600);
+    assert_eq!(
+        q.convert_with_next_change(hour).1,
+        Some(Duration::new(0, 1))
+    );
+    assert_eq!(
+        h.convert_with_next_change(hour).1,
+        Some(Duration::from_secs(1620))
+    );
+    for f in [&f, &g, &q, &h, &h2] {
+        for start in (0..3 * 86400).step_by(3001) {
+            let (text, next) = f.convert_with_next_change(Duration::from_secs(start));
+            let mut end = start + 1;
+            while f.convert(Duration::from_secs(end)) == text {
+                end += 1;
+            }
+            assert_eq!(next, Some(Duration::from_secs(end - start)), "{start}");
+        }
+    }
+}
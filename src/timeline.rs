@@ -0,0 +1,127 @@
+//! Grouping of moments into timeline sections like "Today", "Yesterday" or "Earlier".
+
+use super::{Formatter, Language};
+
+/// Section of a timeline a moment belongs to, relative to the current calendar day.
+/// Weeks start on Monday.
+///
+/// Buckets are ordered from the newest to the oldest, each one also taking the moments
+/// of newer buckets that are disabled with `Formatter::timeline_buckets`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum TimelineBucket {
+    /// Days after today
+    Upcoming,
+    /// The current day
+    Today,
+    /// The previous day
+    Yesterday,
+    /// The current week
+    ThisWeek,
+    /// The previous week
+    LastWeek,
+    /// The current month
+    ThisMonth,
+    /// Anything before, always enabled
+    Older,
+}
+
+impl TimelineBucket {
+    /// All the buckets, from the newest to the oldest
+    pub const ALL: [TimelineBucket; 7] = [
+        TimelineBucket::Upcoming,
+        TimelineBucket::Today,
+        TimelineBucket::Yesterday,
+        TimelineBucket::ThisWeek,
+        TimelineBucket::LastWeek,
+        TimelineBucket::ThisMonth,
+        TimelineBucket::Older,
+    ];
+}
+
+impl<L: Language> Formatter<L> {
+    /// Localized header of the bucket, like "Yesterday" or "Earlier"
+    /// ```
+    /// use timeago::TimelineBucket;
+    /// let f = timeago::Formatter::new();
+    /// assert_eq!(f.timeline_label(TimelineBucket::LastWeek), "Last week");
+    /// assert_eq!(f.timeline_label(TimelineBucket::Older), "Earlier");
+    /// ```
+    pub fn timeline_label(&self, bucket: TimelineBucket) -> &'static str {
+        self.lang.timeline_label(bucket)
+    }
+
+    /// Bucket of the moment `from` relative to `now`, with calendar days taken in the time zone `tz`.
+    ///
+    /// Requires `chrono` Cargo feature.
+    /// ```
+    /// extern crate chrono;
+    /// extern crate timeago;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use timeago::TimelineBucket;
+    /// let mut f = timeago::Formatter::new();
+    /// let tz = FixedOffset::east_opt(3 * 3600).unwrap();
+    /// let now = Utc.with_ymd_and_hms(2024, 3, 14, 10, 0, 0).unwrap(); // Thursday
+    /// let t = |d, h| Utc.with_ymd_and_hms(2024, 3, d, h, 0, 0).unwrap();
+    /// assert_eq!(f.timeline_bucket(t(14, 1), now, &tz), TimelineBucket::Today);
+    /// assert_eq!(f.timeline_bucket(t(13, 22), now, &tz), TimelineBucket::Today);
+    /// assert_eq!(f.timeline_bucket(t(13, 12), now, &tz), TimelineBucket::Yesterday);
+    /// assert_eq!(f.timeline_bucket(t(11, 12), now, &tz), TimelineBucket::ThisWeek);
+    /// assert_eq!(f.timeline_bucket(t(5, 12), now, &tz), TimelineBucket::LastWeek);
+    /// assert_eq!(f.timeline_bucket(t(1, 12), now, &tz), TimelineBucket::ThisMonth);
+    /// assert_eq!(f.timeline_bucket(t(16, 12), now, &tz), TimelineBucket::Upcoming);
+    ///
+    /// f.timeline_buckets(&[TimelineBucket::Today, TimelineBucket::ThisMonth]);
+    /// assert_eq!(f.timeline_bucket(t(16, 12), now, &tz), TimelineBucket::Today);
+    /// assert_eq!(f.timeline_bucket(t(11, 12), now, &tz), TimelineBucket::ThisMonth);
+    /// let bucket = f.timeline_bucket(Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap(), now, &tz);
+    /// assert_eq!(f.timeline_label(bucket), "Earlier");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn timeline_bucket<Tz1, Tz2, Tz3>(
+        &self,
+        from: chrono::DateTime<Tz1>,
+        now: chrono::DateTime<Tz2>,
+        tz: &Tz3,
+    ) -> TimelineBucket
+    where
+        Tz1: chrono::TimeZone,
+        Tz2: chrono::TimeZone,
+        Tz3: chrono::TimeZone,
+    {
+        use super::calendar::calendar_diff;
+        let (days, weeks, months, _) = calendar_diff(
+            from.with_timezone(tz).date_naive(),
+            now.with_timezone(tz).date_naive(),
+        );
+        self.timeline_buckets
+            .iter()
+            .copied()
+            .find(|b| match b {
+                TimelineBucket::Upcoming => days < 0,
+                TimelineBucket::Today => days <= 0,
+                TimelineBucket::Yesterday => days <= 1,
+                TimelineBucket::ThisWeek => weeks <= 0,
+                TimelineBucket::LastWeek => weeks <= 1,
+                TimelineBucket::ThisMonth => months <= 0,
+                TimelineBucket::Older => true,
+            })
+            .unwrap_or(TimelineBucket::Older)
+    }
+}
+
+#[cfg(all(feature = "chrono", feature = "translations"))]
+#[test]
+fn test_labels() {
+    use super::languages::german::German;
+    use chrono::{TimeZone, Utc};
+    let f = Formatter::with_language(German);
+    let now = Utc.with_ymd_and_hms(2024, 3, 11, 10, 0, 0).unwrap(); // Monday
+    let yesterday = Utc.with_ymd_and_hms(2024, 3, 10, 10, 0, 0).unwrap();
+    let bucket = f.timeline_bucket(yesterday, now, &Utc);
+    assert_eq!(f.timeline_label(bucket), "Gestern");
+    let mut f = Formatter::with_language(German);
+    f.timeline_buckets(&[TimelineBucket::Today, TimelineBucket::ThisWeek]);
+    // Sunday is not in the current week
+    let bucket = f.timeline_bucket(yesterday, now, &Utc);
+    assert_eq!(f.timeline_label(bucket), "Früher");
+}